@@ -1,9 +1,9 @@
 //! Usage: [DY]LD_LIBRARY_PATH="$(git rev-parse --show-toplevel)/build/lib/api" cargo run --example hello 1 2 3
+//!    or, linking WasmEdge statically: cargo run --features static --example hello 1 2 3
 
 #[cfg_attr(test, test)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut args: Vec<String> = std::env::args().collect();
-    let args: Vec<&str> = args.iter_mut().map(|arg| arg.as_str()).collect();
+    let args: Vec<String> = std::env::args().collect();
     if args.len() <= 1 {
         println!("Rust: No input args.");
     }
@@ -13,14 +13,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let config = wasmedge_sdk::Config::with_wasi();
     let module = wasmedge_sdk::Module::new(&config, &module_path)?;
 
+    let wasi_config = args.into_iter().fold(
+        wasmedge_sdk::WasiConfig::new(),
+        wasmedge_sdk::WasiConfig::arg,
+    );
+    // or
+    // let wasi_config = wasi_config.env("KEY", "VALUE").inherit_env();
+
     let vm = wasmedge_sdk::Vm::load(&module)?
         .with_config(&config)?
+        .with_wasi_config(wasi_config)?
         .create()?;
 
-    vm.init_wasi_obj().with_args(args).build();
-    // or
-    // vm.init_wasi_obj().with_args(args).with_envs(envs).build();
-
     let results = vm.run("_start", &[])?;
 
     assert_eq!(results.len(), 0);
@@ -1,4 +1,5 @@
 //! Usage: [DY]LD_LIBRARY_PATH="$(git rev-parse --show-toplevel)/build/lib/api" cargo run --example quickstart
+//!    or, linking WasmEdge statically: cargo run --features static --example quickstart
 
 #[cfg_attr(test, test)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
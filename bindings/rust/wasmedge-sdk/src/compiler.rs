@@ -0,0 +1,129 @@
+use super::wasmedge;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{error::CompilerError, module::path_to_cstr, Config};
+use sha2::{Digest, Sha256};
+
+/// The AOT compiler, configured by the optimization level and the IR dumping
+/// option of a [`Config`].
+///
+/// # Example
+///
+/// ```ignore
+///     let config = wasmedge_sdk::Config::default();
+///     let compiler = wasmedge_sdk::Compiler::new(&config);
+///
+///     let compiled = compiler.compile_cached(&module_path, &cache_dir)?;
+///     let module = wasmedge_sdk::Module::new(&config, &compiled)?;
+/// ```
+#[derive(Debug)]
+pub struct Compiler {
+    inner: wasmedge::Compiler,
+}
+
+impl Compiler {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            inner: wasmedge::Compiler::create(&config.inner),
+        }
+    }
+
+    /// Compiles the Wasm file `input` into `output`.
+    pub fn compile(&self, input: &Path, output: &Path) -> Result<(), anyhow::Error> {
+        let in_path = path_to_cstr(input)
+            .map_err(|e| CompilerError::Path(input.display().to_string(), Box::new(e)))?;
+        let out_path = path_to_cstr(output)
+            .map_err(|e| CompilerError::Path(output.display().to_string(), Box::new(e)))?;
+
+        self.inner
+            .compile(in_path, out_path)
+            .map_err(CompilerError::Compile)?;
+        Ok(())
+    }
+
+    /// Compiles the Wasm file `input` and returns the compiled module.
+    pub fn compile_to_bytes(&self, input: &Path) -> Result<Vec<u8>, anyhow::Error> {
+        let output = tempfile::Builder::new()
+            .prefix("wasmedge-aot-")
+            .suffix(".wasm")
+            .tempfile()
+            .map_err(CompilerError::Io)?
+            .into_temp_path();
+        self.compile(input, &output)?;
+        Ok(fs::read(&output).map_err(CompilerError::Io)?)
+    }
+
+    /// Compiles the Wasm file `input` into `cache_dir`, unless a module with
+    /// the same content was compiled there before, and returns the path of
+    /// the compiled module.
+    ///
+    /// Modules are keyed on the SHA-256 of their content and of the WasmEdge
+    /// version, but not on the configuration, so compilers with different
+    /// options should not share a cache directory.
+    pub fn compile_cached(&self, input: &Path, cache_dir: &Path) -> Result<PathBuf, anyhow::Error> {
+        let wasm = fs::read(input).map_err(CompilerError::Io)?;
+        let hash = Sha256::new()
+            .chain(wasmedge::full_version())
+            .chain(&wasm)
+            .finalize();
+        let cached = cache_dir.join(format!("{:x}.wasm", hash));
+        if cached.is_file() {
+            return Ok(cached);
+        }
+
+        // Compile next to the cache entry and rename it, so that concurrent
+        // builds never see a partially written module.
+        fs::create_dir_all(cache_dir).map_err(CompilerError::Io)?;
+        let output = tempfile::Builder::new()
+            .suffix(".wasm")
+            .tempfile_in(cache_dir)
+            .map_err(CompilerError::Io)?
+            .into_temp_path();
+        self.compile(input, &output)?;
+        output
+            .persist(&cached)
+            .map_err(|e| CompilerError::Io(e.error))?;
+        Ok(cached)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Module, Vm};
+
+    #[test]
+    fn compiles_and_caches_modules() -> Result<(), anyhow::Error> {
+        let module_path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let cache_dir = tempfile::tempdir()?;
+        let config = Config::default();
+        let compiler = Compiler::new(&config);
+
+        let compiled = compiler.compile_cached(&module_path, cache_dir.path())?;
+        assert_eq!(
+            compiler.compile_cached(&module_path, cache_dir.path())?,
+            compiled
+        );
+        assert_eq!(fs::read_dir(cache_dir.path())?.count(), 1);
+        assert!(!compiler.compile_to_bytes(&module_path)?.is_empty());
+
+        let module = Module::new(&config, &compiled)?;
+        let mut vm = Vm::load(&module)?.with_config(&config)?.create()?;
+        assert_eq!(vm.typed_func::<i32, i32>("fib")?.call(10)?, 89);
+
+        let err = compiler
+            .compile(&cache_dir.path().join("missing.wasm"), &compiled)
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<CompilerError>()
+                .and_then(CompilerError::kind),
+            Some(wasmedge::ErrorKind::InvalidPath)
+        );
+        Ok(())
+    }
+}
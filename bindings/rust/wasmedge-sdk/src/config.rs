@@ -11,4 +11,34 @@ impl Config {
         let inner: wasmedge::Config = config.inner.enable_wasi();
         Self { inner }
     }
+
+    /// Registers the `wasmedge_process` module, so that guests can run the
+    /// host commands allowed by a [`ProcessConfig`](crate::ProcessConfig).
+    pub fn enable_wasmedge_process(self) -> Self {
+        Self {
+            inner: self.inner.enable_wasmedge_process(),
+        }
+    }
+
+    /// Enables counting the executed instructions, see [`Vm::statistics`](crate::Vm::statistics).
+    pub fn count_instructions(self, enable: bool) -> Self {
+        Self {
+            inner: self.inner.count_instructions(enable),
+        }
+    }
+
+    /// Enables measuring the cost of the executed instructions, so that a
+    /// cost limit can be enforced.
+    pub fn measure_costs(self, enable: bool) -> Self {
+        Self {
+            inner: self.inner.measure_costs(enable),
+        }
+    }
+
+    /// Enables measuring the execution time.
+    pub fn measure_time(self, enable: bool) -> Self {
+        Self {
+            inner: self.inner.measure_time(enable),
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use super::wasmedge;
+use crate::wasi_conf::WasiStdio;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -11,10 +12,80 @@ pub enum ModuleError {
 
     #[error("loader error: {}", _0.message)]
     Load(wasmedge::ErrReport),
+
+    #[cfg(feature = "wat")]
+    #[error("invalid text format: {0}")]
+    Wat(wat::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum CompilerError {
+    #[error("`{0}` is not a valid path: {1}")]
+    Path(String, Box<dyn std::error::Error + 'static + Send + Sync>),
+
+    #[error("compilation failed: {}", _0.message)]
+    Compile(wasmedge::ErrReport),
+
+    #[error("compiler I/O error: {0}")]
+    Io(std::io::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum WasiError {
+    #[error("cannot preopen `{}`: {1}", _0.display())]
+    Dir(std::path::PathBuf, std::io::Error),
+
+    #[error("cannot preopen `{}`: not a directory", _0.display())]
+    NotADirectory(std::path::PathBuf),
+
+    #[error("cannot preopen `{}`: not a valid UTF-8 path", _0.display())]
+    Path(std::path::PathBuf),
+
+    #[error("cannot preopen `{}`: the path ends with `:readonly`", _0.display())]
+    ReadOnlySuffix(std::path::PathBuf),
+
+    #[error("`{0}` is not a valid guest path")]
+    GuestPath(String),
+
+    #[error("guest path `{0}` is preopened twice")]
+    DuplicateGuestPath(String),
+
+    #[error("`{0}` is not a valid environment variable name")]
+    EnvKey(String),
+
+    #[error("WASI arguments and environment variables cannot contain NUL bytes")]
+    Nul(std::ffi::NulError),
+
+    #[error("WASI is not enabled in the config")]
+    NotEnabled,
+
+    #[error("cannot buffer the standard streams: {0}")]
+    Stdio(std::io::Error),
+
+    #[error("WasmEdge cannot redirect the standard streams")]
+    Redirect,
+}
+
+#[derive(Debug, Error)]
+pub enum ImportError {
+    #[error("cannot create global `{0}`: {}", _1.message)]
+    Global(String, wasmedge::ErrReport),
+}
+
+#[derive(Debug, Error)]
+pub enum ProcessError {
+    #[error("commands cannot contain NUL bytes")]
+    Nul(std::ffi::NulError),
+
+    #[error("wasmedge_process is not enabled in the config")]
+    NotEnabled,
 }
 
 #[derive(Debug, Error)]
 pub enum VmError {
+    #[error("module registration failed: {}", _0.message)]
+    Register(wasmedge::ErrReport),
+
     #[error("module loading failed: {}", _0.message)]
     ModuleLoad(wasmedge::ErrReport),
 
@@ -24,9 +95,132 @@ pub enum VmError {
     #[error("module instantiation failed: {}", _0.message)]
     Instantiate(wasmedge::ErrReport),
 
+    #[error("invalid WASI configuration: {0}")]
+    Wasi(WasiError),
+
+    #[error("invalid wasmedge_process configuration: {0}")]
+    Process(ProcessError),
+
+    #[error("unresolved imports: {}", format_imports(_0))]
+    MissingImports(Vec<wasmedge::ImportType>),
+
     #[error("could not find function `{0}` in module")]
     MissingFunction(String),
 
+    #[error("function `{name}` has type {actual}, expected {expected}")]
+    FunctionType {
+        name: String,
+        expected: wasmedge::FuncType,
+        actual: wasmedge::FuncType,
+    },
+
     #[error("module execution failed: {}", _0.message)]
     Execute(wasmedge::ErrReport),
+
+    #[error("module execution exceeded its cost limit")]
+    CostLimitExceeded(wasmedge::ErrReport),
+
+    #[error("module execution was interrupted")]
+    Interrupted,
+
+    #[error("module exited with code {0}")]
+    Exit(i32),
+
+    #[error("cannot forward the output of the guest: {0}")]
+    Stdio(std::io::Error),
+
+    #[cfg(feature = "async")]
+    #[error("`Vm::run_async` requires the multi-threaded tokio runtime")]
+    AsyncRuntime,
+
+    #[cfg(feature = "async")]
+    #[error("async host function `{0}` was called outside of `Vm::run_async`")]
+    AsyncHostFunc(String),
+}
+
+impl ModuleError {
+    /// Returns the kind of the error reported by WasmEdge, if any.
+    pub fn kind(&self) -> Option<wasmedge::ErrorKind> {
+        match self {
+            Self::Unknown(report) | Self::Load(report) => Some(report.kind),
+            Self::Path(..) => None,
+            #[cfg(feature = "wat")]
+            Self::Wat(_) => None,
+        }
+    }
+}
+
+impl CompilerError {
+    /// Returns the kind of the error reported by WasmEdge, if any.
+    pub fn kind(&self) -> Option<wasmedge::ErrorKind> {
+        match self {
+            Self::Compile(report) => Some(report.kind),
+            Self::Path(..) | Self::Io(_) => None,
+        }
+    }
+}
+
+impl VmError {
+    /// Wraps the result of an execution of `vm`, telling a cost limit and an
+    /// interrupt apart, and a WASI exit with a nonzero code from a success. A
+    /// pending interrupt is cleared in any case, and the output of the guest
+    /// is forwarded to the writers of the [`WasiConfig`]. An async host
+    /// function that failed because it was not run by `Vm::run_async` is
+    /// reported as such.
+    ///
+    /// [`WasiConfig`]: crate::WasiConfig
+    pub(crate) fn execution<T>(
+        vm: &mut wasmedge::Vm,
+        stdio: Option<&mut WasiStdio>,
+        res: Result<T, wasmedge::ErrReport>,
+    ) -> Result<T, Self> {
+        let interrupted = vm.statistics_mut().take_interrupt();
+        let flushed = stdio.map_or(Ok(()), WasiStdio::flush);
+        #[cfg(feature = "async")]
+        if let Some(name) = crate::import_obj::take_misplaced_async_call() {
+            return Err(Self::AsyncHostFunc(name));
+        }
+        let value = res.map_err(|report| match report.kind {
+            wasmedge::ErrorKind::CostLimitExceeded if interrupted => Self::Interrupted,
+            wasmedge::ErrorKind::CostLimitExceeded => Self::CostLimitExceeded(report),
+            _ => Self::Execute(report),
+        })?;
+        match vm.wasi_exit_code() {
+            Some(code) if code != 0 => return Err(Self::Exit(code as i32)),
+            _ => {}
+        }
+        flushed.map_err(Self::Stdio)?;
+        Ok(value)
+    }
+
+    /// Returns the kind of the error reported by WasmEdge, e.g. to tell a
+    /// trap of the guest from an invalid module.
+    pub fn kind(&self) -> Option<wasmedge::ErrorKind> {
+        match self {
+            Self::Register(report)
+            | Self::ModuleLoad(report)
+            | Self::Validate(report)
+            | Self::Instantiate(report)
+            | Self::Execute(report)
+            | Self::CostLimitExceeded(report) => Some(report.kind),
+            Self::Wasi(_)
+            | Self::Process(_)
+            | Self::MissingImports(_)
+            | Self::MissingFunction(_)
+            | Self::FunctionType { .. }
+            | Self::Interrupted
+            | Self::Exit(_)
+            | Self::Stdio(_) => None,
+            #[cfg(feature = "async")]
+            Self::AsyncRuntime | Self::AsyncHostFunc(_) => None,
+        }
+    }
+}
+
+fn format_imports(imports: &[wasmedge::ImportType]) -> String {
+    imports
+        .iter()
+        .map(|import| format!("`{}.{}`", import.module, import.name))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
@@ -0,0 +1,172 @@
+use super::wasmedge;
+use crate::error::ImportError;
+#[cfg(feature = "async")]
+use std::cell::{Cell, RefCell};
+
+/// A named module of host functions and globals that guests can import from.
+///
+/// # Example
+///
+/// ```ignore
+///     use wasmedge_sdk::wasmedge::{FuncType, ValType, Value};
+///
+///     let ty = FuncType::new([ValType::I32, ValType::I32], [ValType::I32]);
+///     let import_obj = wasmedge_sdk::ImportObject::new("host").with_func("add", &ty, |params| {
+///         let a = params[0].as_i32().unwrap();
+///         let b = params[1].as_i32().unwrap();
+///         Ok(vec![Value::I32(a + b)])
+///     });
+///
+///     let vm = wasmedge_sdk::Vm::load(&module)?
+///         .with_config(&config)?
+///         .with_import_object(import_obj)?
+///         .create()?;
+/// ```
+#[derive(Debug)]
+pub struct ImportObject {
+    pub(crate) inner: wasmedge::ImportObject,
+}
+
+impl ImportObject {
+    pub fn new(module_name: &str) -> Self {
+        Self {
+            inner: wasmedge::ImportObject::create(module_name),
+        }
+    }
+
+    /// Adds a host function `name` of type `ty` calling `func`.
+    pub fn with_func<F>(mut self, name: &str, ty: &wasmedge::FuncType, func: F) -> Self
+    where
+        F: Fn(&[wasmedge::Value]) -> Result<Vec<wasmedge::Value>, wasmedge::Trap> + Send + 'static,
+    {
+        self.inner
+            .add_function(name, wasmedge::Function::wrap(ty, func));
+        self
+    }
+
+    /// Adds a host global `name` of type `ty` holding `value`, which must be of
+    /// the value type of `ty`.
+    pub fn with_global(
+        mut self,
+        name: &str,
+        ty: wasmedge::GlobalType,
+        value: wasmedge::Value,
+    ) -> Result<Self, anyhow::Error> {
+        let global = wasmedge::Global::create(ty, value)
+            .map_err(|err| ImportError::Global(name.to_string(), err))?;
+        self.inner.add_global(name, global);
+        Ok(self)
+    }
+
+    /// Adds a host function `name` of type `ty` awaiting the future returned
+    /// by `func`.
+    ///
+    /// The guest is blocked until the future completes, which requires it to
+    /// be run by [`Vm::run_async`](crate::Vm::run_async): otherwise the call
+    /// fails with [`VmError::AsyncHostFunc`](crate::error::VmError::AsyncHostFunc).
+    #[cfg(feature = "async")]
+    pub fn with_async_func<F, Fut>(self, name: &str, ty: &wasmedge::FuncType, func: F) -> Self
+    where
+        F: Fn(Vec<wasmedge::Value>) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = Result<Vec<wasmedge::Value>, wasmedge::Trap>>,
+    {
+        let func_name = name.to_string();
+        self.with_func(name, ty, move |params| {
+            // `run_async` runs the guest in `block_in_place`, where the worker
+            // thread may block on a future of the runtime. Blocking anywhere
+            // else would panic, or deadlock the runtime.
+            let handle = match tokio::runtime::Handle::try_current() {
+                Ok(handle) if IN_RUN_ASYNC.with(Cell::get) => handle,
+                _ => {
+                    MISPLACED_ASYNC_CALL.with(|call| *call.borrow_mut() = Some(func_name.clone()));
+                    return Err(wasmedge::Trap::Fail);
+                }
+            };
+            handle.block_on(func(params.to_vec()))
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+thread_local! {
+    // Set while `Vm::run_async` runs a guest on this thread.
+    static IN_RUN_ASYNC: Cell<bool> = const { Cell::new(false) };
+    // The last async host function called on this thread outside of
+    // `Vm::run_async`, reported once the execution fails.
+    static MISPLACED_ASYNC_CALL: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, which runs a guest, letting its async host functions block on
+/// the current runtime.
+#[cfg(feature = "async")]
+pub(crate) fn allow_async_calls<T>(f: impl FnOnce() -> T) -> T {
+    struct Reset(bool);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            IN_RUN_ASYNC.with(|flag| flag.set(self.0));
+        }
+    }
+
+    let _reset = Reset(IN_RUN_ASYNC.with(|flag| flag.replace(true)));
+    f()
+}
+
+/// Returns the async host function that failed on this thread because it was
+/// called outside of `Vm::run_async`, if any.
+#[cfg(feature = "async")]
+pub(crate) fn take_misplaced_async_call() -> Option<String> {
+    MISPLACED_ASYNC_CALL.with(RefCell::take)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        wasmedge::{GlobalType, Mutability, ValType, Value},
+        Config, Module, Vm,
+    };
+
+    #[test]
+    fn provides_host_globals() -> Result<(), anyhow::Error> {
+        // (module
+        //   (import "host" "tenant" (global i64))
+        //   (global (export "stack_pointer") (mut i32) (i32.const 1024))
+        //   (func (export "tenant") (result i64) (global.get 0)))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x05, 0x01, 0x60, 0x00, 0x01,
+            0x7e, 0x02, 0x10, 0x01, 0x04, 0x68, 0x6f, 0x73, 0x74, 0x06, 0x74, 0x65, 0x6e, 0x61,
+            0x6e, 0x74, 0x03, 0x7e, 0x00, 0x03, 0x02, 0x01, 0x00, 0x06, 0x07, 0x01, 0x7f, 0x01,
+            0x41, 0x80, 0x08, 0x0b, 0x07, 0x1a, 0x02, 0x0d, 0x73, 0x74, 0x61, 0x63, 0x6b, 0x5f,
+            0x70, 0x6f, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x03, 0x01, 0x06, 0x74, 0x65, 0x6e, 0x61,
+            0x6e, 0x74, 0x00, 0x00, 0x0a, 0x06, 0x01, 0x04, 0x00, 0x23, 0x00, 0x0b,
+        ];
+        let tenant_ty = GlobalType::new(ValType::I64, Mutability::Const);
+
+        let err = ImportObject::new("host")
+            .with_global("tenant", tenant_ty, Value::I32(7))
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ImportError>(),
+            Some(ImportError::Global(name, _)) if name == "tenant"
+        ));
+
+        let config = Config::default();
+        let module = Module::from_bytes(&config, wasm)?;
+        let import_obj =
+            ImportObject::new("host").with_global("tenant", tenant_ty, Value::I64(7))?;
+        let mut vm = Vm::load(&module)?
+            .with_config(&config)?
+            .with_import_object(import_obj)?
+            .create()?;
+        assert_eq!(vm.typed_func::<(), i64>("tenant")?.call(())?, 7);
+
+        // Imported globals are not exported by the module.
+        assert!(vm.global("tenant").is_none());
+        let mut stack_pointer = vm.global("stack_pointer").unwrap();
+        assert_eq!(stack_pointer.value(), Value::I32(1024));
+        stack_pointer.set(Value::I32(2048)).unwrap();
+        assert_eq!(stack_pointer.value(), Value::I32(2048));
+        Ok(())
+    }
+}
@@ -2,12 +2,23 @@
 
 pub use wasmedge_sys as wasmedge;
 
+pub mod compiler;
 pub mod config;
 pub mod error;
+pub mod import_obj;
+pub mod linker;
 pub mod module;
+pub mod process_conf;
+pub mod typed_func;
 pub mod vm;
 pub mod wasi_conf;
 
+pub use compiler::Compiler;
 pub use config::Config;
+pub use import_obj::ImportObject;
+pub use linker::Linker;
 pub use module::Module;
-pub use vm::Vm;
+pub use process_conf::ProcessConfig;
+pub use typed_func::{TypedFunc, WasmVal, WasmValList};
+pub use vm::{Limits, Vm};
+pub use wasi_conf::{DirPerms, Pipe, WasiConfig};
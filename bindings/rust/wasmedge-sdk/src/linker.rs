@@ -0,0 +1,204 @@
+use super::wasmedge;
+
+use crate::{
+    config::Config,
+    error::{ProcessError, VmError, WasiError},
+    import_obj::ImportObject,
+    module::Module,
+    process_conf::ProcessConfig,
+    vm::Vm,
+    wasi_conf::WasiConfig,
+};
+
+/// Links a module with the named modules and host functions it imports, and
+/// checks that every import is resolved before instantiating it.
+///
+/// # Example
+///
+/// ```ignore
+///     let vm = wasmedge_sdk::Linker::new(&config)
+///         .with_module("math", &math)
+///         .with_import_object(host)
+///         .instantiate(&main)?;
+/// ```
+#[derive(Debug)]
+pub struct Linker<'a> {
+    config: &'a wasmedge::Config,
+    modules: Vec<(String, &'a Module)>,
+    import_objs: Vec<ImportObject>,
+    wasi_config: Option<WasiConfig>,
+    process_config: Option<ProcessConfig>,
+}
+
+impl<'a> Linker<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self::with_config(&config.inner)
+    }
+
+    pub(crate) fn with_config(config: &'a wasmedge::Config) -> Self {
+        Self {
+            config,
+            modules: Vec::new(),
+            import_objs: Vec::new(),
+            wasi_config: None,
+            process_config: None,
+        }
+    }
+
+    /// Registers the exports of `module` under `name`.
+    ///
+    /// Modules are registered in order, so a module can import from the
+    /// modules registered before it.
+    pub fn with_module(mut self, name: &str, module: &'a Module) -> Self {
+        self.modules.push((name.to_string(), module));
+        self
+    }
+
+    /// Registers the host functions of `import_obj` under its module name.
+    pub fn with_import_object(mut self, import_obj: ImportObject) -> Self {
+        self.import_objs.push(import_obj);
+        self
+    }
+
+    /// Initializes the WASI module with `wasi_config`, which requires WASI to
+    /// be enabled in the config.
+    pub fn with_wasi_config(mut self, wasi_config: WasiConfig) -> Self {
+        self.wasi_config = Some(wasi_config);
+        self
+    }
+
+    /// Initializes the `wasmedge_process` module with `process_config`, which
+    /// requires it to be enabled in the config.
+    pub fn with_process_config(mut self, process_config: ProcessConfig) -> Self {
+        self.process_config = Some(process_config);
+        self
+    }
+
+    /// Instantiates `module`, failing with [`VmError::MissingImports`] if
+    /// it or a registered module imports something that was not registered.
+    pub fn instantiate(self, module: &'a Module) -> Result<Vm<'a>, anyhow::Error> {
+        let mut vm = wasmedge::Vm::create(self.config, None);
+        let mut stdio = None;
+        if let Some(wasi_config) = self.wasi_config {
+            if !self.config.is_wasi_enabled() {
+                return Err(VmError::Wasi(WasiError::NotEnabled).into());
+            }
+            let [args, envs, dirs] = wasi_config.to_raw().map_err(VmError::Wasi)?;
+            vm.init_wasi(&args, &envs, &dirs);
+            stdio = wasi_config.open_stdio().map_err(VmError::Wasi)?;
+            if let Some(ref stdio) = stdio {
+                stdio.redirect(&mut vm).map_err(VmError::Wasi)?;
+            }
+        }
+        if let Some(process_config) = self.process_config {
+            if !self.config.is_wasmedge_process_enabled() {
+                return Err(VmError::Process(ProcessError::NotEnabled).into());
+            }
+            let allowed_cmds = process_config.to_raw().map_err(VmError::Process)?;
+            vm.init_wasmedge_process(&allowed_cmds, process_config.allows_all());
+        }
+        for import_obj in self.import_objs {
+            vm = vm
+                .register_module_from_import(import_obj.inner)
+                .map_err(VmError::Register)?;
+        }
+        for (name, registered) in self.modules {
+            check_imports(&mut vm, registered)?;
+            vm = vm
+                .register_module_from_ast(name, &registered.inner)
+                .map_err(VmError::Register)?;
+        }
+
+        check_imports(&mut vm, module)?;
+        let vm = vm
+            .load_wasm_from_ast_module(&module.inner)
+            .map_err(VmError::ModuleLoad)?;
+        let vm = vm.validate().map_err(VmError::Validate)?;
+        let vm = vm.instantiate().map_err(VmError::Instantiate)?;
+
+        Ok(Vm {
+            config: Some(self.config),
+            module,
+            import_objs: Vec::new(),
+            wasi_config: None,
+            process_config: None,
+            stdio,
+            inner: Some(vm),
+        })
+    }
+}
+
+/// Looks up every import of `module` in the modules registered in `vm`.
+fn check_imports(vm: &mut wasmedge::Vm, module: &Module) -> Result<(), VmError> {
+    let store = vm.store_mut();
+    let missing: Vec<_> = module
+        .imports()
+        .filter(|import| match import.ty {
+            wasmedge::ExternType::Func(_) => store
+                .find_function_registered(&import.module, &import.name)
+                .is_none(),
+            wasmedge::ExternType::Table(_) => store
+                .find_table_registered(&import.module, &import.name)
+                .is_none(),
+            wasmedge::ExternType::Memory(_) => store
+                .find_memory_registered(&import.module, &import.name)
+                .is_none(),
+            wasmedge::ExternType::Global(_) => store
+                .find_global_registered(&import.module, &import.name)
+                .is_none(),
+        })
+        .collect();
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(VmError::MissingImports(missing))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasmedge::Value;
+
+    #[test]
+    fn reports_missing_imports() -> Result<(), anyhow::Error> {
+        // (module
+        //   (import "math" "fib" (func (param i32) (result i32)))
+        //   (func (export "fib_plus_one") (param i32) (result i32)
+        //     (i32.add (call 0 (local.get 0)) (i32.const 1))))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x06, 0x01, 0x60, 0x01, 0x7f,
+            0x01, 0x7f, 0x02, 0x0c, 0x01, 0x04, 0x6d, 0x61, 0x74, 0x68, 0x03, 0x66, 0x69, 0x62,
+            0x00, 0x00, 0x03, 0x02, 0x01, 0x00, 0x07, 0x10, 0x01, 0x0c, 0x66, 0x69, 0x62, 0x5f,
+            0x70, 0x6c, 0x75, 0x73, 0x5f, 0x6f, 0x6e, 0x65, 0x00, 0x01, 0x0a, 0x0b, 0x01, 0x09,
+            0x00, 0x20, 0x00, 0x10, 0x00, 0x41, 0x01, 0x6a, 0x0b,
+        ];
+        let module_path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Config::default();
+        let main = Module::from_bytes(&config, wasm)?;
+        let math = Module::new(&config, &module_path)?;
+
+        let err = Linker::new(&config).instantiate(&main).unwrap_err();
+        match err.downcast_ref::<VmError>() {
+            Some(VmError::MissingImports(missing)) => {
+                assert_eq!(missing.len(), 1);
+                assert_eq!(missing[0].module, "math");
+                assert_eq!(missing[0].name, "fib");
+            }
+            _ => panic!("unexpected error: {}", err),
+        }
+        assert_eq!(err.to_string(), "unresolved imports: `math.fib`");
+
+        let mut vm = Linker::new(&config)
+            .with_module("math", &math)
+            .instantiate(&main)?;
+        assert_eq!(vm.typed_func::<i32, i32>("fib_plus_one")?.call(5)?, 9);
+        assert_eq!(
+            vm.run_registered("math", "fib", &[Value::I32(10)])?,
+            vec![Value::I32(89)]
+        );
+        Ok(())
+    }
+}
@@ -23,10 +23,35 @@ impl Module {
 
         Ok(Self { inner: module })
     }
+
+    /// Loads a module from the bytes of a Wasm binary.
+    ///
+    /// With the `wat` feature, `bytes` may also be a module in the text
+    /// format, which is converted to a binary before loading.
+    pub fn from_bytes(config: &Config, bytes: &[u8]) -> Result<Self, anyhow::Error> {
+        #[cfg(feature = "wat")]
+        let bytes = &*wat::parse_bytes(bytes).map_err(ModuleError::Wat)?;
+
+        let module =
+            wasmedge::Module::load_from_buffer(&config.inner, bytes).map_err(ModuleError::Load)?;
+
+        Ok(Self { inner: module })
+    }
+
+    /// Returns the imports of the module, e.g. to check the host modules it
+    /// depends on before instantiating it.
+    pub fn imports(&self) -> impl ExactSizeIterator<Item = wasmedge::ImportType> + '_ {
+        self.inner.imports()
+    }
+
+    /// Returns the exports of the module.
+    pub fn exports(&self) -> impl ExactSizeIterator<Item = wasmedge::ExportType> + '_ {
+        self.inner.exports()
+    }
 }
 
 #[cfg(windows)]
-fn path_to_cstr(path: &Path) -> Result<CString, NulError> {
+pub(crate) fn path_to_cstr(path: &Path) -> Result<CString, NulError> {
     use std::os::windows::ffi::OsStrExt;
     let path_bytes: Vec<u8> = path
         .as_os_str()
@@ -37,7 +62,58 @@ fn path_to_cstr(path: &Path) -> Result<CString, NulError> {
 }
 
 #[cfg(unix)]
-fn path_to_cstr(path: &Path) -> Result<CString, NulError> {
+pub(crate) fn path_to_cstr(path: &Path) -> Result<CString, NulError> {
     use std::os::unix::ffi::OsStrExt;
     CString::new(path.as_os_str().as_bytes().to_vec())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vm;
+
+    #[test]
+    fn loads_module_from_bytes() -> Result<(), anyhow::Error> {
+        let module_path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Config::default();
+        let module = Module::from_bytes(&config, &std::fs::read(module_path)?)?;
+        let mut vm = Vm::load(&module)?.with_config(&config)?.create()?;
+        assert_eq!(vm.typed_func::<i32, i32>("fib")?.call(5)?, 8);
+        assert_eq!(module.imports().len(), 0);
+        assert_eq!(
+            module
+                .exports()
+                .map(|export| export.name)
+                .collect::<Vec<_>>(),
+            vec!["fib".to_string()]
+        );
+
+        let err = Module::from_bytes(&config, b"\0wasm").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ModuleError>(),
+            Some(ModuleError::Load(_))
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "wat")]
+    #[test]
+    fn loads_module_from_text() -> Result<(), anyhow::Error> {
+        let config = Config::default();
+        let module = Module::from_bytes(
+            &config,
+            br#"(module (func (export "add") (param i32 i32) (result i32)
+                  (i32.add (local.get 0) (local.get 1))))"#,
+        )?;
+        let mut vm = Vm::load(&module)?.with_config(&config)?.create()?;
+        assert_eq!(vm.typed_func::<(i32, i32), i32>("add")?.call((2, 3))?, 5);
+
+        let err = Module::from_bytes(&config, b"(module (func").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ModuleError>(),
+            Some(ModuleError::Wat(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use crate::error::ProcessError;
+use std::{collections::BTreeSet, ffi::CString};
+
+/// The host commands a guest may run through the `wasmedge_process` module,
+/// set on a [`VmBuilder`](crate::vm::VmBuilder) or a [`Linker`](crate::Linker)
+/// before instantiation. No command is allowed by default.
+///
+/// # Example
+///
+/// ```ignore
+///     let config = wasmedge_sdk::Config::default().enable_wasmedge_process();
+///     let process = wasmedge_sdk::ProcessConfig::new().allow_cmd("echo");
+///
+///     let vm = wasmedge_sdk::Vm::load(&module)?
+///         .with_config(&config)?
+///         .with_process_config(process)?
+///         .create()?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ProcessConfig {
+    allowed_cmds: BTreeSet<String>,
+    allow_all: bool,
+}
+
+impl ProcessConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows the guest to run `cmd`, which is looked up in the `PATH` of the
+    /// host process unless it is a path.
+    pub fn allow_cmd(mut self, cmd: impl Into<String>) -> Self {
+        self.allowed_cmds.insert(cmd.into());
+        self
+    }
+
+    /// Allows the guest to run any command of the host.
+    pub fn allow_all(mut self) -> Self {
+        self.allow_all = true;
+        self
+    }
+
+    pub(crate) fn allows_all(&self) -> bool {
+        self.allow_all
+    }
+
+    pub(crate) fn to_raw(&self) -> Result<Vec<CString>, ProcessError> {
+        self.allowed_cmds
+            .iter()
+            .map(|cmd| CString::new(cmd.as_str()))
+            .collect::<Result<_, _>>()
+            .map_err(ProcessError::Nul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::VmError, Config, Module, Vm};
+
+    // (module
+    //   (import "wasmedge_process" "wasmedge_process_set_prog_name" (func (param i32 i32)))
+    //   (import "wasmedge_process" "wasmedge_process_add_arg" (func (param i32 i32)))
+    //   (import "wasmedge_process" "wasmedge_process_run" (func (result i32)))
+    //   (import "wasmedge_process" "wasmedge_process_get_stdout_len" (func (result i32)))
+    //   (import "wasmedge_process" "wasmedge_process_get_stdout" (func (param i32)))
+    //   (memory (export "memory") 1)
+    //   (data (i32.const 0) "echo")
+    //   (data (i32.const 16) "hello")
+    //   (func (export "echo") (result i32 i32)
+    //     (call 0 (i32.const 0) (i32.const 4))
+    //     (call 1 (i32.const 16) (i32.const 5))
+    //     (call 2)
+    //     (call 4 (i32.const 32))
+    //     (call 3)))
+    const ECHO_WASM: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x13, 0x04, 0x60, 0x02, 0x7f, 0x7f,
+        0x00, 0x60, 0x00, 0x01, 0x7f, 0x60, 0x01, 0x7f, 0x00, 0x60, 0x00, 0x02, 0x7f, 0x7f, 0x02,
+        0xe9, 0x01, 0x05, 0x10, 0x77, 0x61, 0x73, 0x6d, 0x65, 0x64, 0x67, 0x65, 0x5f, 0x70, 0x72,
+        0x6f, 0x63, 0x65, 0x73, 0x73, 0x1e, 0x77, 0x61, 0x73, 0x6d, 0x65, 0x64, 0x67, 0x65, 0x5f,
+        0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x5f, 0x73, 0x65, 0x74, 0x5f, 0x70, 0x72, 0x6f,
+        0x67, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x00, 0x00, 0x10, 0x77, 0x61, 0x73, 0x6d, 0x65, 0x64,
+        0x67, 0x65, 0x5f, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x18, 0x77, 0x61, 0x73, 0x6d,
+        0x65, 0x64, 0x67, 0x65, 0x5f, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x5f, 0x61, 0x64,
+        0x64, 0x5f, 0x61, 0x72, 0x67, 0x00, 0x00, 0x10, 0x77, 0x61, 0x73, 0x6d, 0x65, 0x64, 0x67,
+        0x65, 0x5f, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x14, 0x77, 0x61, 0x73, 0x6d, 0x65,
+        0x64, 0x67, 0x65, 0x5f, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x5f, 0x72, 0x75, 0x6e,
+        0x00, 0x01, 0x10, 0x77, 0x61, 0x73, 0x6d, 0x65, 0x64, 0x67, 0x65, 0x5f, 0x70, 0x72, 0x6f,
+        0x63, 0x65, 0x73, 0x73, 0x1f, 0x77, 0x61, 0x73, 0x6d, 0x65, 0x64, 0x67, 0x65, 0x5f, 0x70,
+        0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x5f, 0x67, 0x65, 0x74, 0x5f, 0x73, 0x74, 0x64, 0x6f,
+        0x75, 0x74, 0x5f, 0x6c, 0x65, 0x6e, 0x00, 0x01, 0x10, 0x77, 0x61, 0x73, 0x6d, 0x65, 0x64,
+        0x67, 0x65, 0x5f, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x1b, 0x77, 0x61, 0x73, 0x6d,
+        0x65, 0x64, 0x67, 0x65, 0x5f, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x5f, 0x67, 0x65,
+        0x74, 0x5f, 0x73, 0x74, 0x64, 0x6f, 0x75, 0x74, 0x00, 0x02, 0x03, 0x02, 0x01, 0x03, 0x05,
+        0x03, 0x01, 0x00, 0x01, 0x07, 0x11, 0x02, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02,
+        0x00, 0x04, 0x65, 0x63, 0x68, 0x6f, 0x00, 0x05, 0x0a, 0x18, 0x01, 0x16, 0x00, 0x41, 0x00,
+        0x41, 0x04, 0x10, 0x00, 0x41, 0x10, 0x41, 0x05, 0x10, 0x01, 0x10, 0x02, 0x41, 0x20, 0x10,
+        0x04, 0x10, 0x03, 0x0b, 0x0b, 0x14, 0x02, 0x00, 0x41, 0x00, 0x0b, 0x04, 0x65, 0x63, 0x68,
+        0x6f, 0x00, 0x41, 0x10, 0x0b, 0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f,
+    ];
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn runs_allowed_commands() -> Result<(), anyhow::Error> {
+        let config = Config::default().enable_wasmedge_process();
+        let module = Module::from_bytes(&config, ECHO_WASM)?;
+
+        let mut vm = Vm::load(&module)?
+            .with_config(&config)?
+            .with_process_config(ProcessConfig::new().allow_cmd("echo"))?
+            .create()?;
+        let (exit_code, len) = vm.typed_func::<(), (i32, i32)>("echo")?.call(())?;
+        assert_eq!((exit_code, len), (0, 6));
+        let mut stdout = vec![0; len as usize];
+        vm.memory("memory").unwrap().read(32, &mut stdout).unwrap();
+        assert_eq!(stdout, b"hello\n");
+
+        // Commands are denied unless they are allowed.
+        let mut vm = Vm::load(&module)?.with_config(&config)?.create()?;
+        let (exit_code, len) = vm.typed_func::<(), (i32, i32)>("echo")?.call(())?;
+        assert_eq!((exit_code, len), (-1, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn requires_wasmedge_process() -> Result<(), anyhow::Error> {
+        let config = Config::default();
+        let module = Module::from_bytes(&config, ECHO_WASM)?;
+
+        let err = Vm::load(&module)?
+            .with_config(&config)?
+            .with_process_config(ProcessConfig::new().allow_all())?
+            .create()
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<VmError>(),
+            Some(VmError::Process(ProcessError::NotEnabled))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+use super::wasmedge::{self, wasmedge::WasmEdge_Value, FuncType, ValType, Value};
+
+use crate::{error::VmError, wasi_conf::WasiStdio};
+use std::marker::PhantomData;
+
+/// A Rust type that maps to a Wasm value type.
+pub trait WasmVal: Copy {
+    const TYPE: ValType;
+
+    fn into_raw(self) -> WasmEdge_Value;
+
+    /// Decodes a value returned by WasmEdge, whose `Type` is not meaningful.
+    fn from_raw(raw: WasmEdge_Value) -> Self;
+}
+
+/// Code Generate for `WasmVal` :
+///
+/// ```ignore
+/// impl WasmVal for i32 {
+///     const TYPE: ValType = ValType::I32;
+///
+///     fn into_raw(self) -> WasmEdge_Value {
+///         Value::I32(self).into()
+///     }
+///
+///     fn from_raw(raw: WasmEdge_Value) -> Self {
+///         match Value::from_raw(Self::TYPE, raw) {
+///             Value::I32(value) => value,
+///             _ => unreachable!(),
+///         }
+///     }
+/// }
+/// ```
+macro_rules! impl_wasm_val {
+    ($( $ty:ty => $name:ident ),+ $(,)?) => {
+        $(
+            impl WasmVal for $ty {
+                const TYPE: ValType = ValType::$name;
+
+                fn into_raw(self) -> WasmEdge_Value {
+                    Value::$name(self).into()
+                }
+
+                fn from_raw(raw: WasmEdge_Value) -> Self {
+                    match Value::from_raw(Self::TYPE, raw) {
+                        Value::$name(value) => value,
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        )+
+    }
+}
+
+impl_wasm_val! {
+    i32 => I32,
+    i64 => I64,
+    f32 => F32,
+    f64 => F64,
+    u128 => V128,
+}
+
+/// The parameters or results of a [`TypedFunc`]: `()`, a single [`WasmVal`],
+/// or a tuple of them.
+pub trait WasmValList: Sized {
+    /// A fixed-size buffer of raw values, so that calls do not allocate.
+    type Raw: AsRef<[WasmEdge_Value]> + AsMut<[WasmEdge_Value]>;
+
+    fn types() -> Vec<ValType>;
+
+    fn raw_buffer() -> Self::Raw;
+
+    fn into_raw(self) -> Self::Raw;
+
+    fn from_raw(raw: Self::Raw) -> Self;
+}
+
+const RAW_ZERO: WasmEdge_Value = WasmEdge_Value {
+    Value: 0,
+    Type: wasmedge::wasmedge::WasmEdge_ValType_I32,
+};
+
+impl<T: WasmVal> WasmValList for T {
+    type Raw = [WasmEdge_Value; 1];
+
+    fn types() -> Vec<ValType> {
+        vec![T::TYPE]
+    }
+
+    fn raw_buffer() -> Self::Raw {
+        [RAW_ZERO]
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        [self.into_raw()]
+    }
+
+    fn from_raw(raw: Self::Raw) -> Self {
+        T::from_raw(raw[0])
+    }
+}
+
+/// Code Generate for `WasmValList` on tuples :
+///
+/// ```ignore
+/// impl<A: WasmVal, B: WasmVal> WasmValList for (A, B) {
+///     type Raw = [WasmEdge_Value; 2];
+///
+///     fn types() -> Vec<ValType> {
+///         vec![A::TYPE, B::TYPE]
+///     }
+///
+///     fn raw_buffer() -> Self::Raw {
+///         [RAW_ZERO, RAW_ZERO]
+///     }
+///
+///     fn into_raw(self) -> Self::Raw {
+///         let (a, b) = self;
+///         [a.into_raw(), b.into_raw()]
+///     }
+///
+///     fn from_raw(raw: Self::Raw) -> Self {
+///         let [a, b] = raw;
+///         (A::from_raw(a), B::from_raw(b))
+///     }
+/// }
+/// ```
+macro_rules! impl_wasm_val_list {
+    ($( $len:literal => ($($name:ident),*) ),+ $(,)?) => {
+        paste::paste! {
+            $(
+                #[allow(unused_variables, clippy::unused_unit)]
+                impl<$($name: WasmVal),*> WasmValList for ($($name,)*) {
+                    type Raw = [WasmEdge_Value; $len];
+
+                    fn types() -> Vec<ValType> {
+                        vec![$($name::TYPE),*]
+                    }
+
+                    fn raw_buffer() -> Self::Raw {
+                        [RAW_ZERO; $len]
+                    }
+
+                    fn into_raw(self) -> Self::Raw {
+                        let ($([<$name:lower>],)*) = self;
+                        [$([<$name:lower>].into_raw()),*]
+                    }
+
+                    fn from_raw(raw: Self::Raw) -> Self {
+                        let [$([<$name:lower>]),*] = raw;
+                        ($($name::from_raw([<$name:lower>]),)*)
+                    }
+                }
+            )+
+        }
+    }
+}
+
+impl_wasm_val_list! {
+    0 => (),
+    1 => (A),
+    2 => (A, B),
+    3 => (A, B, C),
+    4 => (A, B, C, D),
+    5 => (A, B, C, D, E),
+    6 => (A, B, C, D, E, F),
+    7 => (A, B, C, D, E, F, G),
+    8 => (A, B, C, D, E, F, G, H),
+}
+
+/// An exported function whose signature has been checked against `Params` and
+/// `Results` when it was looked up with [`Vm::typed_func`](crate::Vm::typed_func).
+///
+/// # Example
+///
+/// ```ignore
+///     let mut vm = wasmedge_sdk::Vm::load(&module)?.with_config(&config)?.create()?;
+///
+///     let mut fib = vm.typed_func::<i32, i32>("fib")?;
+///     assert_eq!(fib.call(5)?, 8);
+/// ```
+#[derive(Debug)]
+pub struct TypedFunc<'vm, Params, Results> {
+    vm: &'vm mut wasmedge::Vm,
+    stdio: Option<&'vm mut WasiStdio>,
+    name: String,
+    ty: PhantomData<fn(Params) -> Results>,
+}
+
+impl<'vm, Params, Results> TypedFunc<'vm, Params, Results>
+where
+    Params: WasmValList,
+    Results: WasmValList,
+{
+    pub(crate) fn new(
+        vm: &'vm mut wasmedge::Vm,
+        stdio: Option<&'vm mut WasiStdio>,
+        name: &str,
+    ) -> Result<Self, VmError> {
+        let actual = vm
+            .function_type(name)
+            .ok_or_else(|| VmError::MissingFunction(name.to_string()))?;
+        let expected = FuncType::new(Params::types(), Results::types());
+        if actual != expected {
+            return Err(VmError::FunctionType {
+                name: name.to_string(),
+                expected,
+                actual,
+            });
+        }
+
+        Ok(Self {
+            vm,
+            stdio,
+            name: name.to_string(),
+            ty: PhantomData,
+        })
+    }
+
+    pub fn call(&mut self, params: Params) -> Result<Results, anyhow::Error> {
+        let params = params.into_raw();
+        let mut returns = Results::raw_buffer();
+        let res = self
+            .vm
+            .execute(&self.name, params.as_ref(), returns.as_mut());
+        VmError::execution(self.vm, self.stdio.as_deref_mut(), res)?;
+        Ok(Results::from_raw(returns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::VmError, Config, Module, Vm};
+
+    #[test]
+    fn checks_signature_once() -> Result<(), anyhow::Error> {
+        let module_path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Config::default();
+        let module = Module::new(&config, &module_path)?;
+        let mut vm = Vm::load(&module)?.with_config(&config)?.create()?;
+
+        let mut fib = vm.typed_func::<i32, i32>("fib")?;
+        assert_eq!(fib.call(5)?, 8);
+        assert_eq!(fib.call(10)?, 89);
+
+        let err = vm.typed_func::<(i32, i32), i64>("fib").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<VmError>(),
+            Some(VmError::FunctionType { .. })
+        ));
+
+        let err = vm.typed_func::<i32, i32>("fac").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<VmError>(),
+            Some(VmError::MissingFunction(_))
+        ));
+        Ok(())
+    }
+}
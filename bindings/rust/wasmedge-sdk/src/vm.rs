@@ -1,6 +1,20 @@
 use super::wasmedge;
 
-use crate::{config::Config, error::VmError, module::Module, wasi_conf::WasiConf};
+use crate::{
+    config::Config,
+    error::VmError,
+    import_obj::ImportObject,
+    linker::Linker,
+    module::Module,
+    process_conf::ProcessConfig,
+    typed_func::{TypedFunc, WasmValList},
+    wasi_conf::{WasiConfig, WasiStdio},
+};
+use std::{
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
 
 /// # Example
 ///
@@ -24,8 +38,12 @@ use crate::{config::Config, error::VmError, module::Module, wasi_conf::WasiConf}
 ///
 #[derive(Debug)]
 pub struct Vm<'a> {
-    config: Option<&'a wasmedge::Config>,
-    module: &'a Module,
+    pub(crate) config: Option<&'a wasmedge::Config>,
+    pub(crate) module: &'a Module,
+    pub(crate) import_objs: Vec<ImportObject>,
+    pub(crate) wasi_config: Option<WasiConfig>,
+    pub(crate) process_config: Option<ProcessConfig>,
+    pub(crate) stdio: Option<WasiStdio>,
     pub(crate) inner: Option<wasmedge::Vm>,
 }
 
@@ -34,8 +52,187 @@ impl<'a> Vm<'a> {
         VmBuilder::new(module)
     }
 
-    pub fn init_wasi_obj(&self) -> WasiConf<'_> {
-        WasiConf::new(self)
+    /// Looks up the exported function `func_name` and checks that its
+    /// signature is `Params -> Results`, so that it can be called without
+    /// converting values.
+    pub fn typed_func<Params, Results>(
+        &mut self,
+        func_name: &str,
+    ) -> Result<TypedFunc<'_, Params, Results>, anyhow::Error>
+    where
+        Params: WasmValList,
+        Results: WasmValList,
+    {
+        match self.inner {
+            Some(ref mut vm) => Ok(TypedFunc::new(vm, self.stdio.as_mut(), func_name)?),
+            None => panic!("WasmEdge Vm can't run!"),
+        }
+    }
+
+    /// Returns the exported memory `name` of the module.
+    pub fn memory(&mut self, name: &str) -> Option<wasmedge::Memory<'_>> {
+        match self.inner {
+            Some(ref mut vm) => vm.store_mut().find_memory(name),
+            None => panic!("WasmEdge Vm can't run!"),
+        }
+    }
+
+    /// Returns the exported table `name` of the module, e.g. to install the
+    /// functions called indirectly by the guest.
+    pub fn table(&mut self, name: &str) -> Option<wasmedge::Table<'_>> {
+        match self.inner {
+            Some(ref mut vm) => vm.store_mut().find_table(name),
+            None => panic!("WasmEdge Vm can't run!"),
+        }
+    }
+
+    /// Returns the exported global `name` of the module.
+    pub fn global(&mut self, name: &str) -> Option<wasmedge::Global<'_>> {
+        match self.inner {
+            Some(ref mut vm) => vm.store_mut().find_global(name),
+            None => panic!("WasmEdge Vm can't run!"),
+        }
+    }
+
+    /// Returns the statistics of the executions, as enabled by the [`Config`].
+    pub fn statistics(&self) -> &wasmedge::Statistics {
+        match self.inner {
+            Some(ref vm) => vm.statistics(),
+            None => panic!("WasmEdge Vm can't run!"),
+        }
+    }
+
+    /// Returns the statistics of the executions, e.g. to set a cost limit
+    /// before running a function.
+    pub fn statistics_mut(&mut self) -> &mut wasmedge::Statistics {
+        match self.inner {
+            Some(ref mut vm) => vm.statistics_mut(),
+            None => panic!("WasmEdge Vm can't run!"),
+        }
+    }
+
+    /// Returns a handle to interrupt the running function from another
+    /// thread, which then fails with [`VmError::Interrupted`].
+    ///
+    /// This requires [`Config::measure_costs`], see [`wasmedge::InterruptHandle`].
+    pub fn interrupt_handle(&self) -> wasmedge::InterruptHandle {
+        self.statistics().interrupt_handle()
+    }
+
+    /// Returns the code passed to `proc_exit` if the last execution was
+    /// terminated by the guest, which is reported as [`VmError::Exit`] unless
+    /// the code is 0.
+    pub fn wasi_exit_code(&self) -> Option<i32> {
+        match self.inner {
+            Some(ref vm) => vm.wasi_exit_code().map(|code| code as i32),
+            None => panic!("WasmEdge Vm can't run!"),
+        }
+    }
+
+    /// Runs the function `func_name` within `limits`, failing with
+    /// [`VmError::CostLimitExceeded`] past `max_cost` and with
+    /// [`VmError::Interrupted`] past `timeout`.
+    ///
+    /// The limits are checked before each instruction of the guest, so a
+    /// host function blocking past the timeout is not interrupted before it
+    /// returns. Any limit requires [`Config::measure_costs`].
+    ///
+    /// Modules compiled ahead of time by the [`Compiler`](crate::Compiler)
+    /// only check the limits when calling host functions, so the limits do
+    /// not stop them otherwise.
+    pub fn run_with_limits(
+        &mut self,
+        func_name: &str,
+        params: &[wasmedge::Value],
+        limits: Limits,
+    ) -> Result<Vec<wasmedge::Value>, anyhow::Error> {
+        let vm = match self.inner {
+            Some(ref mut vm) => vm,
+            None => panic!("WasmEdge Vm can't run!"),
+        };
+        let measuring = matches!(self.config, Some(config) if config.is_cost_measuring());
+        if limits != Limits::default() && !measuring {
+            anyhow::bail!("execution limits require `Config::measure_costs`");
+        }
+
+        let stats = vm.statistics_mut();
+        let cost_limit = stats.cost_limit();
+        if let Some(max_cost) = limits.max_cost {
+            // The total cost adds up across calls.
+            let limit = stats.total_cost().saturating_add(max_cost);
+            stats.set_cost_limit(limit.min(cost_limit));
+        }
+        let watchdog = limits.timeout.map(|timeout| {
+            let handle = stats.interrupt_handle();
+            let (done, finished) = mpsc::channel::<()>();
+            let thread = thread::spawn(move || {
+                if let Err(RecvTimeoutError::Timeout) = finished.recv_timeout(timeout) {
+                    handle.interrupt();
+                }
+            });
+            (done, thread)
+        });
+
+        let res = vm.run(func_name, params);
+        if let Some((done, thread)) = watchdog {
+            drop(done);
+            thread.join().expect("watchdog thread panicked");
+        }
+        vm.statistics_mut().set_cost_limit(cost_limit);
+        Ok(VmError::execution(vm, self.stdio.as_mut(), res)?)
+    }
+
+    /// Runs the function `func_name` exported by the registered module
+    /// `mod_name`.
+    pub fn run_registered(
+        &mut self,
+        mod_name: &str,
+        func_name: &str,
+        params: &[wasmedge::Value],
+    ) -> Result<Vec<wasmedge::Value>, anyhow::Error> {
+        match self.inner {
+            Some(ref mut vm) => {
+                let res = vm.run_registered(mod_name, func_name, params);
+                Ok(VmError::execution(vm, self.stdio.as_mut(), res)?)
+            }
+            None => panic!("WasmEdge Vm can't run!"),
+        }
+    }
+
+    /// Runs the function `func_name` from an async task, so that its
+    /// [async host functions](crate::ImportObject::with_async_func) can await
+    /// the futures of the runtime.
+    ///
+    /// The guest runs on the current worker thread, whose other tasks are
+    /// handed to the other workers in the meantime, as with
+    /// [`tokio::task::block_in_place`]. Therefore, this requires the
+    /// multi-threaded runtime and fails with [`VmError::AsyncRuntime`] on the
+    /// current-thread one.
+    ///
+    /// The returned future is `Send`, so a `'static` vm can run in a task of
+    /// its own with [`tokio::spawn`].
+    #[cfg(feature = "async")]
+    pub async fn run_async(
+        &mut self,
+        func_name: &str,
+        params: &[wasmedge::Value],
+    ) -> Result<Vec<wasmedge::Value>, anyhow::Error> {
+        use tokio::runtime::{Handle, RuntimeFlavor};
+
+        let vm = match self.inner {
+            Some(ref mut vm) => vm,
+            None => panic!("WasmEdge Vm can't run!"),
+        };
+        match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {}
+            _ => return Err(VmError::AsyncRuntime.into()),
+        }
+
+        let stdio = self.stdio.as_mut();
+        tokio::task::block_in_place(|| {
+            let res = crate::import_obj::allow_async_calls(|| vm.run(func_name, params));
+            Ok(VmError::execution(vm, stdio, res)?)
+        })
     }
 
     pub fn run(
@@ -45,14 +242,28 @@ impl<'a> Vm<'a> {
     ) -> Result<Vec<wasmedge::Value>, anyhow::Error> {
         match self.inner {
             Some(ref mut vm) => {
-                let returns = vm.run(func_name, params).map_err(VmError::Execute)?;
-                Ok(returns)
+                let res = vm.run(func_name, params);
+                Ok(VmError::execution(vm, self.stdio.as_mut(), res)?)
             }
             None => panic!("WasmEdge Vm can't run!"),
         }
     }
 }
 
+/// The limits of a call to [`Vm::run_with_limits`], none by default.
+///
+/// They are enforced by the interpreter only: modules compiled ahead of time
+/// by the [`Compiler`](crate::Compiler) are stopped at their next host
+/// function call at the earliest.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// The maximum cost of the instructions executed by the call, on top of
+    /// the cost limit of the [statistics](Vm::statistics).
+    pub max_cost: Option<u64>,
+    /// The maximum wall-clock time of the call.
+    pub timeout: Option<Duration>,
+}
+
 #[derive(Debug)]
 pub struct VmBuilder<'a> {
     pub inner: Vm<'a>,
@@ -63,6 +274,10 @@ impl<'a> VmBuilder<'a> {
         let vm = Vm {
             config: None,
             module,
+            import_objs: Vec::new(),
+            wasi_config: None,
+            process_config: None,
+            stdio: None,
             inner: None,
         };
         Ok(Self { inner: vm })
@@ -74,23 +289,249 @@ impl<'a> VmBuilder<'a> {
         Ok(Self { inner: vm })
     }
 
+    /// Registers the host functions of `import_obj` for the module to import.
+    pub fn with_import_object(self, import_obj: ImportObject) -> Result<Self, anyhow::Error> {
+        let mut vm = self.inner;
+        vm.import_objs.push(import_obj);
+        Ok(Self { inner: vm })
+    }
+
+    /// Initializes the WASI module with `wasi_config` before instantiating the
+    /// module, which requires WASI to be enabled in the config.
+    pub fn with_wasi_config(self, wasi_config: WasiConfig) -> Result<Self, anyhow::Error> {
+        let mut vm = self.inner;
+        vm.wasi_config = Some(wasi_config);
+        Ok(Self { inner: vm })
+    }
+
+    /// Initializes the `wasmedge_process` module with `process_config` before
+    /// instantiating the module, which requires it to be enabled in the config.
+    pub fn with_process_config(self, process_config: ProcessConfig) -> Result<Self, anyhow::Error> {
+        let mut vm = self.inner;
+        vm.process_config = Some(process_config);
+        Ok(Self { inner: vm })
+    }
+
     pub fn create(self) -> Result<Vm<'a>, anyhow::Error> {
         let vm = self.inner;
         if let Some(cfg) = vm.config {
-            let vm_instance = wasmedge::Vm::create(cfg);
-            let vm_instance = vm_instance
-                .load_wasm_from_ast_module(&vm.module.inner)
-                .map_err(VmError::ModuleLoad)?;
-            let vm_instance = vm_instance.validate().map_err(VmError::Validate)?;
-            let vm_instance = vm_instance.instantiate().map_err(VmError::Instantiate)?;
-
-            Ok(Vm {
-                config: vm.config,
-                module: vm.module,
-                inner: Some(vm_instance),
-            })
+            let mut linker = vm
+                .import_objs
+                .into_iter()
+                .fold(Linker::with_config(cfg), Linker::with_import_object);
+            if let Some(wasi_config) = vm.wasi_config {
+                linker = linker.with_wasi_config(wasi_config);
+            }
+            if let Some(process_config) = vm.process_config {
+                linker = linker.with_process_config(process_config);
+            }
+            linker.instantiate(vm.module)
         } else {
             anyhow::bail!("no config provided to VM");
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_at_cost_limit() -> Result<(), anyhow::Error> {
+        let module_path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Config::default()
+            .count_instructions(true)
+            .measure_costs(true);
+        let module = Module::new(&config, &module_path)?;
+        let mut vm = Vm::load(&module)?.with_config(&config)?.create()?;
+
+        vm.typed_func::<i32, i32>("fib")?.call(5)?;
+        assert!(vm.statistics().instr_count() > 0);
+
+        let cost = vm.statistics().total_cost();
+        vm.statistics_mut().set_cost_limit(cost + 10);
+        let err = vm.typed_func::<i32, i32>("fib")?.call(20).unwrap_err();
+        let err = err.downcast_ref::<VmError>().unwrap();
+        assert!(matches!(err, VmError::CostLimitExceeded(_)));
+        assert!(err.kind().unwrap().is_trap());
+        Ok(())
+    }
+
+    #[test]
+    fn runs_vms_on_threads_from_a_shared_module() -> Result<(), anyhow::Error> {
+        let module_path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = std::sync::Arc::new(Config::default());
+        let module = std::sync::Arc::new(Module::new(&config, &module_path)?);
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let config = std::sync::Arc::clone(&config);
+                let module = std::sync::Arc::clone(&module);
+                std::thread::spawn(move || -> Result<i32, anyhow::Error> {
+                    let mut vm = Vm::load(&module)?.with_config(&config)?.create()?;
+                    vm.typed_func::<i32, i32>("fib")?.call(10 + i)
+                })
+            })
+            .collect();
+        let results = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(results, vec![89, 144, 233, 377]);
+        Ok(())
+    }
+
+    #[test]
+    fn stops_at_limits_and_interrupts() -> Result<(), anyhow::Error> {
+        // (module
+        //   (func (export "spin") (loop (br 0)))
+        //   (func (export "answer") (result i32) (i32.const 42)))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x02, 0x60, 0x00, 0x00,
+            0x60, 0x00, 0x01, 0x7f, 0x03, 0x03, 0x02, 0x00, 0x01, 0x07, 0x11, 0x02, 0x04, 0x73,
+            0x70, 0x69, 0x6e, 0x00, 0x00, 0x06, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x00, 0x01,
+            0x0a, 0x0e, 0x02, 0x07, 0x00, 0x03, 0x40, 0x0c, 0x00, 0x0b, 0x0b, 0x04, 0x00, 0x41,
+            0x2a, 0x0b,
+        ];
+        let config = Config::default().measure_costs(true);
+        let module = Module::from_bytes(&config, wasm)?;
+        let mut vm = Vm::load(&module)?.with_config(&config)?.create()?;
+
+        let limits = Limits {
+            max_cost: Some(1000),
+            ..Limits::default()
+        };
+        let err = vm.run_with_limits("spin", &[], limits).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<VmError>(),
+            Some(VmError::CostLimitExceeded(_))
+        ));
+
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(50)),
+            ..Limits::default()
+        };
+        let err = vm.run_with_limits("spin", &[], limits).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<VmError>(),
+            Some(VmError::Interrupted)
+        ));
+
+        let handle = vm.interrupt_handle();
+        let interrupter = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            handle.interrupt();
+        });
+        let err = vm.typed_func::<(), ()>("spin")?.call(()).unwrap_err();
+        interrupter.join().unwrap();
+        assert!(matches!(
+            err.downcast_ref::<VmError>(),
+            Some(VmError::Interrupted)
+        ));
+
+        // The limits do not outlive the calls.
+        let results = vm.run_with_limits("answer", &[], Limits::default())?;
+        assert_eq!(results[0].as_i32(), Some(42));
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn awaits_async_host_functions() -> Result<(), anyhow::Error> {
+        use tokio::sync::{mpsc, oneshot};
+        use wasmedge::{FuncType, Trap, ValType, Value};
+
+        // (module
+        //   (import "db" "query" (func (param i32) (result i32)))
+        //   (func (export "lookup") (param i32) (result i32)
+        //     (i32.add (call 0 (local.get 0)) (i32.const 1))))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x06, 0x01, 0x60, 0x01, 0x7f,
+            0x01, 0x7f, 0x02, 0x0c, 0x01, 0x02, 0x64, 0x62, 0x05, 0x71, 0x75, 0x65, 0x72, 0x79,
+            0x00, 0x00, 0x03, 0x02, 0x01, 0x00, 0x07, 0x0a, 0x01, 0x06, 0x6c, 0x6f, 0x6f, 0x6b,
+            0x75, 0x70, 0x00, 0x01, 0x0a, 0x0b, 0x01, 0x09, 0x00, 0x20, 0x00, 0x10, 0x00, 0x41,
+            0x01, 0x6a, 0x0b,
+        ];
+
+        // A mock database, answering the square of the key after a delay.
+        let (db, mut requests) = mpsc::channel::<(i32, oneshot::Sender<i32>)>(1);
+        tokio::spawn(async move {
+            while let Some((key, reply)) = requests.recv().await {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                let _ = reply.send(key * key);
+            }
+        });
+
+        let ty = FuncType::new([ValType::I32], [ValType::I32]);
+        let import_obj = ImportObject::new("db").with_async_func("query", &ty, move |params| {
+            let db = db.clone();
+            async move {
+                let key = params[0].as_i32().ok_or(Trap::Fail)?;
+                let (reply, value) = oneshot::channel();
+                db.send((key, reply)).await.map_err(|_| Trap::Fail)?;
+                Ok(vec![Value::I32(value.await.map_err(|_| Trap::Fail)?)])
+            }
+        });
+
+        let config = Config::default();
+        let module = Module::from_bytes(&config, wasm)?;
+        let mut vm = Vm::load(&module)?
+            .with_config(&config)?
+            .with_import_object(import_obj)?
+            .create()?;
+
+        assert_eq!(
+            vm.run_async("lookup", &[Value::I32(7)]).await?,
+            vec![Value::I32(50)]
+        );
+
+        // Blocking on the runtime is only allowed within `run_async`.
+        let err = vm
+            .run_registered("db", "query", &[Value::I32(7)])
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<VmError>(),
+            Some(VmError::AsyncHostFunc(name)) if name == "query"
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn spawns_async_runs() -> Result<(), anyhow::Error> {
+        let module_path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        // `tokio::spawn` needs a `'static` future, hence a `'static` vm.
+        let config: &'static Config = Box::leak(Box::new(Config::default()));
+        let module: &'static Module = Box::leak(Box::new(Module::new(config, &module_path)?));
+        let mut vm = Vm::load(module)?.with_config(config)?.create()?;
+
+        let results =
+            tokio::spawn(async move { vm.run_async("fib", &[wasmedge::Value::I32(5)]).await })
+                .await??;
+        assert_eq!(results, vec![wasmedge::Value::I32(8)]);
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn rejects_current_thread_runtime() -> Result<(), anyhow::Error> {
+        let module_path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Config::default();
+        let module = Module::new(&config, &module_path)?;
+        let mut vm = Vm::load(&module)?.with_config(&config)?.create()?;
+
+        let err = vm
+            .run_async("fib", &[wasmedge::Value::I32(5)])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<VmError>(),
+            Some(VmError::AsyncRuntime)
+        ));
+        Ok(())
+    }
+}
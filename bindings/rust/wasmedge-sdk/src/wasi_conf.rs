@@ -1,61 +1,665 @@
-use crate::vm::Vm;
+use super::wasmedge;
 
-#[derive(Debug)]
-pub struct WasiConf<'a> {
-    pub vm: &'a Vm<'a>,
-    pub args: Option<Vec<&'a str>>,
-    pub envs: Option<Vec<&'a str>>,
-    pub dirs: Option<Vec<&'a str>>,
-    pub preopens: Option<Vec<&'a str>>,
+use crate::error::WasiError;
+use std::{
+    collections::BTreeMap,
+    ffi::CString,
+    fmt,
+    fs::File,
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    thread,
+};
+
+/// The access of a guest to a preopened directory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DirPerms {
+    ReadOnly,
+    ReadWrite,
 }
 
-impl<'a> WasiConf<'a> {
-    pub fn new(vm: &'a Vm<'a>) -> Self {
-        Self {
-            vm,
-            args: None,
-            envs: None,
-            dirs: None,
-            preopens: None,
+/// The arguments, environment variables and preopened directories of a WASI
+/// guest, set on a [`VmBuilder`](crate::vm::VmBuilder) or a
+/// [`Linker`](crate::Linker) before instantiation.
+///
+/// # Example
+///
+/// ```ignore
+///     let wasi = wasmedge_sdk::WasiConfig::new()
+///         .arg("app.wasm")
+///         .env("LOG", "debug")
+///         .preopen_dir("/srv/data", "/data", DirPerms::ReadOnly)?;
+///
+///     let vm = wasmedge_sdk::Vm::load(&module)?
+///         .with_config(&config)?
+///         .with_wasi_config(wasi)?
+///         .create()?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct WasiConfig {
+    args: Vec<String>,
+    envs: BTreeMap<String, String>,
+    preopens: Vec<Preopen>,
+    stdin: Option<Stream<dyn Read + Send>>,
+    stdout: Option<Stream<dyn Write + Send>>,
+    stderr: Option<Stream<dyn Write + Send>>,
+}
+
+#[derive(Clone, Debug)]
+struct Preopen {
+    host: PathBuf,
+    guest: String,
+    perms: DirPerms,
+}
+
+impl WasiConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a command line argument. By convention, the first one is the
+    /// name of the program.
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Sets the environment variable `key`, replacing any previous value.
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.envs.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the environment variables of the host process, keeping the
+    /// values set before.
+    pub fn inherit_env(mut self) -> Self {
+        for (key, value) in std::env::vars() {
+            self.envs.entry(key).or_insert(value);
         }
+        self
     }
 
-    pub fn with_args(self, args: Vec<&'a str>) -> Self {
-        Self {
-            args: Some(args),
-            ..self
+    /// Maps the directory `host` to the path `guest` of the guest.
+    ///
+    /// The guest path is normalized like WasmEdge does, so `data`, `/data`,
+    /// `./data` and `//data/` are the same path. Fails if `host` is not an
+    /// existing directory or ends with `:readonly`, which WasmEdge would take
+    /// for the permissions, or if `guest` is already preopened.
+    pub fn preopen_dir(
+        mut self,
+        host: impl AsRef<Path>,
+        guest: &str,
+        perms: DirPerms,
+    ) -> Result<Self, WasiError> {
+        let host = host.as_ref();
+        let canonical =
+            std::fs::canonicalize(host).map_err(|err| WasiError::Dir(host.to_path_buf(), err))?;
+        if !canonical.is_dir() {
+            return Err(WasiError::NotADirectory(host.to_path_buf()));
         }
+        match canonical.to_str() {
+            None => return Err(WasiError::Path(host.to_path_buf())),
+            Some(path) if path.ends_with(":readonly") => {
+                return Err(WasiError::ReadOnlySuffix(host.to_path_buf()))
+            }
+            Some(_) => {}
+        }
+
+        // The guest path is separated from the host path by a colon.
+        if guest.is_empty() || guest.contains(':') || guest.contains('\0') {
+            return Err(WasiError::GuestPath(guest.to_string()));
+        }
+        let guest = canonical_guest(guest);
+        if self.preopens.iter().any(|preopen| preopen.guest == guest) {
+            return Err(WasiError::DuplicateGuestPath(guest));
+        }
+
+        self.preopens.push(Preopen {
+            host: canonical,
+            guest,
+            perms,
+        });
+        Ok(self)
     }
 
-    pub fn with_envs(self, envs: Vec<&'a str>) -> Self {
-        Self {
-            envs: Some(envs),
-            ..self
+    /// Sets the standard input of the guest.
+    ///
+    /// A background thread started when the module is instantiated feeds
+    /// `stdin` to the guest through a pipe as the guest reads it, so it may
+    /// never end. The guest reads the end of its input once `stdin` ends or
+    /// fails to read. The thread stops after that, or once the VM is dropped
+    /// and the thread next writes to the pipe.
+    ///
+    /// The clones of the config share `stdin`, which the thread holds until it
+    /// stops: a VM instantiated from a clone meanwhile reads nothing until
+    /// the previous one's thread is done, so clones cannot feed two VMs at
+    /// once.
+    pub fn stdin(mut self, stdin: impl Read + Send + 'static) -> Self {
+        self.stdin = Some(Stream::reader(stdin));
+        self
+    }
+
+    /// Sets the standard output of the guest, whose output is written to
+    /// `stdout` at the end of each call, e.g. into a [`Pipe`].
+    pub fn stdout(mut self, stdout: impl Write + Send + 'static) -> Self {
+        self.stdout = Some(Stream::writer(stdout));
+        self
+    }
+
+    /// Sets the standard error of the guest, whose output is written to
+    /// `stderr` at the end of each call, e.g. into a [`Pipe`].
+    pub fn stderr(mut self, stderr: impl Write + Send + 'static) -> Self {
+        self.stderr = Some(Stream::writer(stderr));
+        self
+    }
+
+    /// Converts the config to the arguments, `KEY=VALUE` pairs and
+    /// directory mappings expected by WasmEdge.
+    pub(crate) fn to_raw(&self) -> Result<[Vec<CString>; 3], WasiError> {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| CString::new(arg.as_str()))
+            .collect::<Result<_, _>>()
+            .map_err(WasiError::Nul)?;
+        let envs = self
+            .envs
+            .iter()
+            .map(|(key, value)| {
+                if key.is_empty() || key.contains('=') {
+                    return Err(WasiError::EnvKey(key.clone()));
+                }
+                CString::new(format!("{}={}", key, value)).map_err(WasiError::Nul)
+            })
+            .collect::<Result<_, _>>()?;
+        let dirs = self
+            .preopens
+            .iter()
+            .map(|preopen| {
+                // Checked to be valid UTF-8 without NUL bytes when preopened.
+                let mut dir = format!("{}:{}", preopen.guest, preopen.host.display());
+                if preopen.perms == DirPerms::ReadOnly {
+                    dir.push_str(":readonly");
+                }
+                CString::new(dir).map_err(WasiError::Nul)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok([args, envs, dirs])
+    }
+
+    /// Creates the files backing the standard streams that are set, if any.
+    pub(crate) fn open_stdio(&self) -> Result<Option<WasiStdio>, WasiError> {
+        if self.stdin.is_none() && self.stdout.is_none() && self.stderr.is_none() {
+            return Ok(None);
         }
+
+        // WasmEdge can only redirect the streams to descriptors on unix.
+        if cfg!(not(unix)) {
+            return Err(WasiError::Redirect);
+        }
+        Ok(Some(WasiStdio {
+            #[cfg(unix)]
+            stdin: match self.stdin {
+                Some(ref stdin) => Some(Input::feed(stdin).map_err(WasiError::Stdio)?),
+                None => None,
+            },
+            stdout: Output::open(self.stdout.as_ref())?,
+            stderr: Output::open(self.stderr.as_ref())?,
+        }))
     }
+}
 
-    pub fn with_dirs(self, dirs: Vec<&'a str>) -> Self {
-        Self {
-            dirs: Some(dirs),
-            ..self
+// Normalizes a guest path as `VINode::canonicalGuest` does, except that `.`
+// components are dropped, so that equal paths are preopened once.
+fn canonical_guest(path: &str) -> String {
+    let mut parts = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            _ => parts.push(part),
         }
     }
+    format!("/{}", parts.join("/"))
+}
+
+/// An in-memory buffer shared by its clones, to provide the input of a guest
+/// or to capture its output.
+///
+/// # Example
+///
+/// ```ignore
+///     let stdout = wasmedge_sdk::Pipe::new();
+///     let wasi = wasmedge_sdk::WasiConfig::new().stdout(stdout.clone());
+///
+///     // ...
+///     vm.run_registered("app", "greet", &[])?;
+///     assert_eq!(stdout.drain(), b"hello\n");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Pipe {
+    buf: Arc<Mutex<Vec<u8>>>,
+}
+
+impl Pipe {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of the bytes written and not read yet.
+    pub fn contents(&self) -> Vec<u8> {
+        self.buf().clone()
+    }
 
-    pub fn with_preopens(self, preopens: Vec<&'a str>) -> Self {
+    /// Returns the bytes written and not read yet, emptying the pipe, e.g. to
+    /// capture the output of each call separately.
+    pub fn drain(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.buf())
+    }
+
+    fn buf(&self) -> MutexGuard<'_, Vec<u8>> {
+        self.buf.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl From<Vec<u8>> for Pipe {
+    fn from(buf: Vec<u8>) -> Self {
         Self {
-            preopens: Some(preopens),
-            ..self
+            buf: Arc::new(Mutex::new(buf)),
         }
     }
+}
 
-    pub fn build(&self) {
-        if let Some(vm) = &self.vm.inner {
-            vm.init_wasi_obj(
-                self.args.as_ref(),
-                self.envs.as_ref(),
-                self.dirs.as_ref(),
-                self.preopens.as_ref(),
-            );
+impl Read for Pipe {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        let mut buf = self.buf();
+        let len = out.len().min(buf.len());
+        out[..len].copy_from_slice(&buf[..len]);
+        buf.drain(..len);
+        Ok(len)
+    }
+}
+
+impl Write for Pipe {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.buf().extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A reader or writer set as a standard stream, shared by the clones of a
+/// [`WasiConfig`].
+struct Stream<T: ?Sized>(Arc<Mutex<T>>);
+
+impl<T: ?Sized> Stream<T> {
+    fn lock(&self) -> MutexGuard<'_, T> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Stream<dyn Read + Send> {
+    fn reader(stdin: impl Read + Send + 'static) -> Self {
+        Self(Arc::new(Mutex::new(stdin)))
+    }
+}
+
+impl Stream<dyn Write + Send> {
+    fn writer(output: impl Write + Send + 'static) -> Self {
+        Self(Arc::new(Mutex::new(output)))
+    }
+}
+
+impl<T: ?Sized> Clone for Stream<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Stream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Stream { .. }")
+    }
+}
+
+/// The files backing the standard streams of a guest, which shares their
+/// offsets through duplicated descriptors.
+#[derive(Debug)]
+pub(crate) struct WasiStdio {
+    #[cfg(unix)]
+    stdin: Option<Input>,
+    stdout: Option<Output>,
+    stderr: Option<Output>,
+}
+
+/// The read end of a pipe fed with the standard input by a background thread,
+/// which holds the shared input until it stops.
+#[cfg(unix)]
+#[derive(Debug)]
+struct Input(std::os::unix::net::UnixStream);
+
+#[cfg(unix)]
+impl Input {
+    fn feed(stdin: &Stream<dyn Read + Send>) -> io::Result<Self> {
+        let (reader, mut writer) = std::os::unix::net::UnixStream::pair()?;
+        let stdin = stdin.clone();
+        thread::Builder::new()
+            .name("wasi-stdin".to_string())
+            .spawn(move || {
+                // Dropping the writer ends the input of the guest, whether the
+                // reader ended or failed, or the guest is gone.
+                let _ = io::copy(&mut *stdin.lock(), &mut writer);
+            })?;
+        Ok(Self(reader))
+    }
+}
+
+#[derive(Debug)]
+struct Output {
+    file: File,
+    sink: Stream<dyn Write + Send>,
+}
+
+impl Output {
+    fn open(sink: Option<&Stream<dyn Write + Send>>) -> Result<Option<Self>, WasiError> {
+        match sink {
+            Some(sink) => Ok(Some(Self {
+                file: tempfile::tempfile().map_err(WasiError::Stdio)?,
+                sink: sink.clone(),
+            })),
+            None => Ok(None),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.seek(SeekFrom::Start(0))?;
+        let mut sink = self.sink.lock();
+        io::copy(&mut self.file, &mut *sink)?;
+        sink.flush()?;
+        // Rewinding the shared offset makes the guest write from the start.
+        self.file.set_len(0)?;
+        self.file.seek(SeekFrom::Start(0))?;
+        Ok(())
+    }
+}
+
+impl WasiStdio {
+    /// Redirects the standard streams of the WASI module of `vm` to the files.
+    #[cfg(unix)]
+    pub(crate) fn redirect(&self, vm: &mut wasmedge::Vm) -> Result<(), WasiError> {
+        use std::os::unix::io::AsRawFd;
+
+        let redirected = vm.set_wasi_stdio(
+            self.stdin.as_ref().map(|input| input.0.as_raw_fd()),
+            self.stdout.as_ref().map(|output| output.file.as_raw_fd()),
+            self.stderr.as_ref().map(|output| output.file.as_raw_fd()),
+        );
+        if redirected {
+            Ok(())
+        } else {
+            Err(WasiError::Redirect)
+        }
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn redirect(&self, _vm: &mut wasmedge::Vm) -> Result<(), WasiError> {
+        Err(WasiError::Redirect)
+    }
+
+    /// Writes the output of the guest since the last call to the writers.
+    pub(crate) fn flush(&mut self) -> io::Result<()> {
+        if let Some(ref mut stdout) = self.stdout {
+            stdout.flush()?;
+        }
+        if let Some(ref mut stderr) = self.stderr {
+            stderr.flush()?;
         }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::VmError, Config, Module, Vm};
+
+    #[test]
+    fn configures_wasi_before_instantiation() -> Result<(), anyhow::Error> {
+        // (module
+        //   (import "wasi_snapshot_preview1" "args_sizes_get" (func (param i32 i32) (result i32)))
+        //   (import "wasi_snapshot_preview1" "environ_sizes_get" (func (param i32 i32) (result i32)))
+        //   (import "wasi_snapshot_preview1" "fd_prestat_get" (func (param i32 i32) (result i32)))
+        //   (memory (export "memory") 1)
+        //   (func (export "arg_count") (result i32)
+        //     (drop (call 0 (i32.const 0) (i32.const 4)))
+        //     (i32.load (i32.const 0)))
+        //   (func (export "env_count") (result i32)
+        //     (drop (call 1 (i32.const 0) (i32.const 4)))
+        //     (i32.load (i32.const 0)))
+        //   (func (export "preopen_name_len") (param i32) (result i32)
+        //     (if (call 2 (local.get 0) (i32.const 0))
+        //       (then (return (i32.const -1))))
+        //     (i32.load (i32.const 4))))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x10, 0x03, 0x60, 0x02, 0x7f,
+            0x7f, 0x01, 0x7f, 0x60, 0x00, 0x01, 0x7f, 0x60, 0x01, 0x7f, 0x01, 0x7f, 0x02, 0x7c,
+            0x03, 0x16, 0x77, 0x61, 0x73, 0x69, 0x5f, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f,
+            0x74, 0x5f, 0x70, 0x72, 0x65, 0x76, 0x69, 0x65, 0x77, 0x31, 0x0e, 0x61, 0x72, 0x67,
+            0x73, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x73, 0x5f, 0x67, 0x65, 0x74, 0x00, 0x00, 0x16,
+            0x77, 0x61, 0x73, 0x69, 0x5f, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x5f,
+            0x70, 0x72, 0x65, 0x76, 0x69, 0x65, 0x77, 0x31, 0x11, 0x65, 0x6e, 0x76, 0x69, 0x72,
+            0x6f, 0x6e, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x73, 0x5f, 0x67, 0x65, 0x74, 0x00, 0x00,
+            0x16, 0x77, 0x61, 0x73, 0x69, 0x5f, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74,
+            0x5f, 0x70, 0x72, 0x65, 0x76, 0x69, 0x65, 0x77, 0x31, 0x0e, 0x66, 0x64, 0x5f, 0x70,
+            0x72, 0x65, 0x73, 0x74, 0x61, 0x74, 0x5f, 0x67, 0x65, 0x74, 0x00, 0x00, 0x03, 0x04,
+            0x03, 0x01, 0x01, 0x02, 0x05, 0x03, 0x01, 0x00, 0x01, 0x07, 0x35, 0x04, 0x06, 0x6d,
+            0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, 0x09, 0x61, 0x72, 0x67, 0x5f, 0x63, 0x6f,
+            0x75, 0x6e, 0x74, 0x00, 0x03, 0x09, 0x65, 0x6e, 0x76, 0x5f, 0x63, 0x6f, 0x75, 0x6e,
+            0x74, 0x00, 0x04, 0x10, 0x70, 0x72, 0x65, 0x6f, 0x70, 0x65, 0x6e, 0x5f, 0x6e, 0x61,
+            0x6d, 0x65, 0x5f, 0x6c, 0x65, 0x6e, 0x00, 0x05, 0x0a, 0x33, 0x03, 0x0e, 0x00, 0x41,
+            0x00, 0x41, 0x04, 0x10, 0x00, 0x1a, 0x41, 0x00, 0x28, 0x02, 0x00, 0x0b, 0x0e, 0x00,
+            0x41, 0x00, 0x41, 0x04, 0x10, 0x01, 0x1a, 0x41, 0x00, 0x28, 0x02, 0x00, 0x0b, 0x13,
+            0x00, 0x20, 0x00, 0x41, 0x00, 0x10, 0x02, 0x04, 0x40, 0x41, 0x7f, 0x0f, 0x0b, 0x41,
+            0x04, 0x28, 0x02, 0x00, 0x0b,
+        ];
+        let dir = tempfile::tempdir()?;
+        let wasi = WasiConfig::new()
+            .arg("app.wasm")
+            .arg("--verbose")
+            .env("LOG", "info")
+            .env("HOME", "/")
+            .env("LOG", "debug")
+            .preopen_dir(dir.path(), "/data/", DirPerms::ReadOnly)?;
+
+        let config = Config::with_wasi();
+        let module = Module::from_bytes(&config, wasm)?;
+        let mut vm = Vm::load(&module)?
+            .with_config(&config)?
+            .with_wasi_config(wasi)?
+            .create()?;
+
+        assert_eq!(vm.typed_func::<(), i32>("arg_count")?.call(())?, 2);
+        assert_eq!(vm.typed_func::<(), i32>("env_count")?.call(())?, 2);
+        let mut name_len = vm.typed_func::<i32, i32>("preopen_name_len")?;
+        // WasmEdge names the preopen without its leading slash.
+        assert_eq!(name_len.call(3)?, "data".len() as i32);
+        assert_eq!(name_len.call(4)?, -1);
+
+        let err = Vm::load(&module)?
+            .with_config(&Config::default())?
+            .with_wasi_config(WasiConfig::new())?
+            .create()
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<VmError>(),
+            Some(VmError::Wasi(WasiError::NotEnabled))
+        ));
+        Ok(())
+    }
+
+    // (module
+    //   (import "wasi_snapshot_preview1" "fd_read" (func (param i32 i32 i32 i32) (result i32)))
+    //   (import "wasi_snapshot_preview1" "fd_write" (func (param i32 i32 i32 i32) (result i32)))
+    //   (memory (export "memory") 1)
+    //   (data (i32.const 100) "done\n")
+    //   (func (export "echo")
+    //     (i32.store (i32.const 0) (i32.const 16))
+    //     (i32.store (i32.const 4) (i32.const 64))
+    //     (drop (call 0 (i32.const 0) (i32.const 0) (i32.const 1) (i32.const 8)))
+    //     (i32.store (i32.const 4) (i32.load (i32.const 8)))
+    //     (drop (call 1 (i32.const 1) (i32.const 0) (i32.const 1) (i32.const 8)))
+    //     (i32.store (i32.const 0) (i32.const 100))
+    //     (i32.store (i32.const 4) (i32.const 5))
+    //     (drop (call 1 (i32.const 2) (i32.const 0) (i32.const 1) (i32.const 8)))))
+    const ECHO: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x0c, 0x02, 0x60, 0x04, 0x7f, 0x7f,
+        0x7f, 0x7f, 0x01, 0x7f, 0x60, 0x00, 0x00, 0x02, 0x44, 0x02, 0x16, 0x77, 0x61, 0x73, 0x69,
+        0x5f, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x5f, 0x70, 0x72, 0x65, 0x76, 0x69,
+        0x65, 0x77, 0x31, 0x07, 0x66, 0x64, 0x5f, 0x72, 0x65, 0x61, 0x64, 0x00, 0x00, 0x16, 0x77,
+        0x61, 0x73, 0x69, 0x5f, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x5f, 0x70, 0x72,
+        0x65, 0x76, 0x69, 0x65, 0x77, 0x31, 0x08, 0x66, 0x64, 0x5f, 0x77, 0x72, 0x69, 0x74, 0x65,
+        0x00, 0x00, 0x03, 0x02, 0x01, 0x01, 0x05, 0x03, 0x01, 0x00, 0x01, 0x07, 0x11, 0x02, 0x06,
+        0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, 0x04, 0x65, 0x63, 0x68, 0x6f, 0x00, 0x02,
+        0x0a, 0x4d, 0x01, 0x4b, 0x00, 0x41, 0x00, 0x41, 0x10, 0x36, 0x02, 0x00, 0x41, 0x04, 0x41,
+        0xc0, 0x00, 0x36, 0x02, 0x00, 0x41, 0x00, 0x41, 0x00, 0x41, 0x01, 0x41, 0x08, 0x10, 0x00,
+        0x1a, 0x41, 0x04, 0x41, 0x08, 0x28, 0x02, 0x00, 0x36, 0x02, 0x00, 0x41, 0x01, 0x41, 0x00,
+        0x41, 0x01, 0x41, 0x08, 0x10, 0x01, 0x1a, 0x41, 0x00, 0x41, 0xe4, 0x00, 0x36, 0x02, 0x00,
+        0x41, 0x04, 0x41, 0x05, 0x36, 0x02, 0x00, 0x41, 0x02, 0x41, 0x00, 0x41, 0x01, 0x41, 0x08,
+        0x10, 0x01, 0x1a, 0x0b, 0x0b, 0x0c, 0x01, 0x00, 0x41, 0xe4, 0x00, 0x0b, 0x05, 0x64, 0x6f,
+        0x6e, 0x65, 0x0a,
+    ];
+
+    #[test]
+    fn captures_stdio_per_call() -> Result<(), anyhow::Error> {
+        let stdout = Pipe::new();
+        let stderr = Pipe::new();
+        let wasi = WasiConfig::new()
+            .stdin(Pipe::from(b"ping\n".to_vec()))
+            .stdout(stdout.clone())
+            .stderr(stderr.clone());
+
+        let config = Config::with_wasi();
+        let module = Module::from_bytes(&config, ECHO)?;
+        let mut vm = Vm::load(&module)?
+            .with_config(&config)?
+            .with_wasi_config(wasi)?
+            .create()?;
+
+        vm.typed_func::<(), ()>("echo")?.call(())?;
+        assert_eq!(stdout.drain(), b"ping\n");
+        assert_eq!(stderr.contents(), b"done\n");
+
+        // The input is consumed by the first call.
+        vm.typed_func::<(), ()>("echo")?.call(())?;
+        assert!(stdout.drain().is_empty());
+        assert_eq!(stderr.contents(), b"done\ndone\n");
+        Ok(())
+    }
+
+    #[test]
+    fn streams_endless_stdin() -> Result<(), anyhow::Error> {
+        let stdout = Pipe::new();
+        let wasi = WasiConfig::new()
+            .stdin(io::repeat(b'y'))
+            .stdout(stdout.clone());
+
+        let config = Config::with_wasi();
+        let module = Module::from_bytes(&config, ECHO)?;
+        let mut vm = Vm::load(&module)?
+            .with_config(&config)?
+            .with_wasi_config(wasi)?
+            .create()?;
+
+        vm.typed_func::<(), ()>("echo")?.call(())?;
+        let echoed = stdout.drain();
+        assert!(!echoed.is_empty());
+        assert!(echoed.iter().all(|&byte| byte == b'y'));
+        Ok(())
+    }
+
+    #[test]
+    fn reports_exit_code() -> Result<(), anyhow::Error> {
+        // (module
+        //   (import "wasi_snapshot_preview1" "proc_exit" (func (param i32)))
+        //   (memory (export "memory") 1)
+        //   (func (export "exit") (param i32)
+        //     (call 0 (local.get 0))))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x05, 0x01, 0x60, 0x01, 0x7f,
+            0x00, 0x02, 0x24, 0x01, 0x16, 0x77, 0x61, 0x73, 0x69, 0x5f, 0x73, 0x6e, 0x61, 0x70,
+            0x73, 0x68, 0x6f, 0x74, 0x5f, 0x70, 0x72, 0x65, 0x76, 0x69, 0x65, 0x77, 0x31, 0x09,
+            0x70, 0x72, 0x6f, 0x63, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x00, 0x00, 0x03, 0x02, 0x01,
+            0x00, 0x05, 0x03, 0x01, 0x00, 0x01, 0x07, 0x11, 0x02, 0x06, 0x6d, 0x65, 0x6d, 0x6f,
+            0x72, 0x79, 0x02, 0x00, 0x04, 0x65, 0x78, 0x69, 0x74, 0x00, 0x01, 0x0a, 0x08, 0x01,
+            0x06, 0x00, 0x20, 0x00, 0x10, 0x00, 0x0b,
+        ];
+        let config = Config::with_wasi();
+        let module = Module::from_bytes(&config, wasm)?;
+        let mut vm = Vm::load(&module)?
+            .with_config(&config)?
+            .with_wasi_config(WasiConfig::new())?
+            .create()?;
+
+        vm.typed_func::<i32, ()>("exit")?.call(0)?;
+        assert_eq!(vm.wasi_exit_code(), Some(0));
+
+        let err = vm.typed_func::<i32, ()>("exit")?.call(3).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<VmError>(),
+            Some(VmError::Exit(3))
+        ));
+        assert_eq!(vm.wasi_exit_code(), Some(3));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_preopens() -> Result<(), anyhow::Error> {
+        let dir = tempfile::tempdir()?;
+        let file = tempfile::NamedTempFile::new_in(dir.path())?;
+
+        let err = WasiConfig::new()
+            .preopen_dir(dir.path().join("missing"), "/data", DirPerms::ReadWrite)
+            .unwrap_err();
+        assert!(matches!(err, WasiError::Dir(..)));
+
+        let err = WasiConfig::new()
+            .preopen_dir(file.path(), "/data", DirPerms::ReadWrite)
+            .unwrap_err();
+        assert!(matches!(err, WasiError::NotADirectory(_)));
+
+        let err = WasiConfig::new()
+            .preopen_dir(dir.path(), "/data:rw", DirPerms::ReadWrite)
+            .unwrap_err();
+        assert!(matches!(err, WasiError::GuestPath(_)));
+
+        let err = WasiConfig::new()
+            .preopen_dir(dir.path(), "/data", DirPerms::ReadWrite)?
+            .preopen_dir(dir.path(), "/data/", DirPerms::ReadOnly)
+            .unwrap_err();
+        assert!(matches!(err, WasiError::DuplicateGuestPath(_)));
+        for guest in &["data", "./data", "//data", "/tmp/../data"] {
+            let err = WasiConfig::new()
+                .preopen_dir(dir.path(), "/data", DirPerms::ReadWrite)?
+                .preopen_dir(dir.path(), guest, DirPerms::ReadWrite)
+                .unwrap_err();
+            assert!(matches!(err, WasiError::DuplicateGuestPath(_)));
+        }
+
+        let readonly = dir.path().join("logs:readonly");
+        std::fs::create_dir(&readonly)?;
+        let err = WasiConfig::new()
+            .preopen_dir(&readonly, "/logs", DirPerms::ReadWrite)
+            .unwrap_err();
+        assert!(matches!(err, WasiError::ReadOnlySuffix(_)));
+
+        let err = WasiConfig::new().env("A=B", "C").to_raw().unwrap_err();
+        assert!(matches!(err, WasiError::EnvKey(_)));
+        Ok(())
     }
 }
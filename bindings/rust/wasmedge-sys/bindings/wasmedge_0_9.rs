@@ -0,0 +1,1326 @@
+/* automatically generated by rust-bindgen 0.59.2 */
+
+pub type WasmEdge_Proposal = ::std::os::raw::c_uint;
+pub const WasmEdge_Proposal_ImportExportMutGlobals: WasmEdge_Proposal = 0;
+pub const WasmEdge_Proposal_NonTrapFloatToIntConversions: WasmEdge_Proposal = 1;
+pub const WasmEdge_Proposal_SignExtensionOperators: WasmEdge_Proposal = 2;
+pub const WasmEdge_Proposal_MultiValue: WasmEdge_Proposal = 3;
+pub const WasmEdge_Proposal_BulkMemoryOperations: WasmEdge_Proposal = 4;
+pub const WasmEdge_Proposal_ReferenceTypes: WasmEdge_Proposal = 5;
+pub const WasmEdge_Proposal_SIMD: WasmEdge_Proposal = 6;
+pub const WasmEdge_Proposal_TailCall: WasmEdge_Proposal = 7;
+pub const WasmEdge_Proposal_Annotations: WasmEdge_Proposal = 8;
+pub const WasmEdge_Proposal_Memory64: WasmEdge_Proposal = 9;
+pub const WasmEdge_Proposal_Threads: WasmEdge_Proposal = 10;
+pub const WasmEdge_Proposal_ExceptionHandling: WasmEdge_Proposal = 11;
+pub const WasmEdge_Proposal_FunctionReferences: WasmEdge_Proposal = 12;
+pub type WasmEdge_HostRegistration = ::std::os::raw::c_uint;
+pub const WasmEdge_HostRegistration_Wasi: WasmEdge_HostRegistration = 0;
+pub const WasmEdge_HostRegistration_WasmEdge_Process: WasmEdge_HostRegistration = 1;
+pub type WasmEdge_CompilerOptimizationLevel = ::std::os::raw::c_uint;
+pub const WasmEdge_CompilerOptimizationLevel_O0: WasmEdge_CompilerOptimizationLevel = 0;
+pub const WasmEdge_CompilerOptimizationLevel_O1: WasmEdge_CompilerOptimizationLevel = 1;
+pub const WasmEdge_CompilerOptimizationLevel_O2: WasmEdge_CompilerOptimizationLevel = 2;
+pub const WasmEdge_CompilerOptimizationLevel_O3: WasmEdge_CompilerOptimizationLevel = 3;
+pub const WasmEdge_CompilerOptimizationLevel_Os: WasmEdge_CompilerOptimizationLevel = 4;
+pub const WasmEdge_CompilerOptimizationLevel_Oz: WasmEdge_CompilerOptimizationLevel = 5;
+pub type WasmEdge_ErrCode = ::std::os::raw::c_uint;
+pub const WasmEdge_ErrCode_Success: WasmEdge_ErrCode = 0;
+pub const WasmEdge_ErrCode_Terminated: WasmEdge_ErrCode = 1;
+pub const WasmEdge_ErrCode_RuntimeError: WasmEdge_ErrCode = 2;
+pub const WasmEdge_ErrCode_CostLimitExceeded: WasmEdge_ErrCode = 3;
+pub const WasmEdge_ErrCode_WrongVMWorkflow: WasmEdge_ErrCode = 4;
+pub const WasmEdge_ErrCode_FuncNotFound: WasmEdge_ErrCode = 5;
+pub const WasmEdge_ErrCode_AOTDisabled: WasmEdge_ErrCode = 6;
+pub const WasmEdge_ErrCode_InvalidPath: WasmEdge_ErrCode = 32;
+pub const WasmEdge_ErrCode_ReadError: WasmEdge_ErrCode = 33;
+pub const WasmEdge_ErrCode_UnexpectedEnd: WasmEdge_ErrCode = 34;
+pub const WasmEdge_ErrCode_InvalidMagic: WasmEdge_ErrCode = 35;
+pub const WasmEdge_ErrCode_InvalidVersion: WasmEdge_ErrCode = 36;
+pub const WasmEdge_ErrCode_InvalidSection: WasmEdge_ErrCode = 37;
+pub const WasmEdge_ErrCode_SectionSizeMismatch: WasmEdge_ErrCode = 38;
+pub const WasmEdge_ErrCode_NameSizeOutOfBounds: WasmEdge_ErrCode = 39;
+pub const WasmEdge_ErrCode_JunkSection: WasmEdge_ErrCode = 40;
+pub const WasmEdge_ErrCode_IncompatibleFuncCode: WasmEdge_ErrCode = 41;
+pub const WasmEdge_ErrCode_IncompatibleDataCount: WasmEdge_ErrCode = 42;
+pub const WasmEdge_ErrCode_DataCountRequired: WasmEdge_ErrCode = 43;
+pub const WasmEdge_ErrCode_InvalidImportKind: WasmEdge_ErrCode = 44;
+pub const WasmEdge_ErrCode_InvalidExportKind: WasmEdge_ErrCode = 45;
+pub const WasmEdge_ErrCode_ExpectedZeroByte: WasmEdge_ErrCode = 46;
+pub const WasmEdge_ErrCode_InvalidMut: WasmEdge_ErrCode = 47;
+pub const WasmEdge_ErrCode_TooManyLocals: WasmEdge_ErrCode = 48;
+pub const WasmEdge_ErrCode_InvalidValType: WasmEdge_ErrCode = 49;
+pub const WasmEdge_ErrCode_InvalidElemType: WasmEdge_ErrCode = 50;
+pub const WasmEdge_ErrCode_InvalidRefType: WasmEdge_ErrCode = 51;
+pub const WasmEdge_ErrCode_InvalidUTF8: WasmEdge_ErrCode = 52;
+pub const WasmEdge_ErrCode_IntegerTooLarge: WasmEdge_ErrCode = 53;
+pub const WasmEdge_ErrCode_IntegerTooLong: WasmEdge_ErrCode = 54;
+pub const WasmEdge_ErrCode_InvalidOpCode: WasmEdge_ErrCode = 55;
+pub const WasmEdge_ErrCode_InvalidGrammar: WasmEdge_ErrCode = 56;
+pub const WasmEdge_ErrCode_InvalidAlignment: WasmEdge_ErrCode = 64;
+pub const WasmEdge_ErrCode_TypeCheckFailed: WasmEdge_ErrCode = 65;
+pub const WasmEdge_ErrCode_InvalidLabelIdx: WasmEdge_ErrCode = 66;
+pub const WasmEdge_ErrCode_InvalidLocalIdx: WasmEdge_ErrCode = 67;
+pub const WasmEdge_ErrCode_InvalidFuncTypeIdx: WasmEdge_ErrCode = 68;
+pub const WasmEdge_ErrCode_InvalidFuncIdx: WasmEdge_ErrCode = 69;
+pub const WasmEdge_ErrCode_InvalidTableIdx: WasmEdge_ErrCode = 70;
+pub const WasmEdge_ErrCode_InvalidMemoryIdx: WasmEdge_ErrCode = 71;
+pub const WasmEdge_ErrCode_InvalidGlobalIdx: WasmEdge_ErrCode = 72;
+pub const WasmEdge_ErrCode_InvalidElemIdx: WasmEdge_ErrCode = 73;
+pub const WasmEdge_ErrCode_InvalidDataIdx: WasmEdge_ErrCode = 74;
+pub const WasmEdge_ErrCode_InvalidRefIdx: WasmEdge_ErrCode = 75;
+pub const WasmEdge_ErrCode_ConstExprRequired: WasmEdge_ErrCode = 76;
+pub const WasmEdge_ErrCode_DupExportName: WasmEdge_ErrCode = 77;
+pub const WasmEdge_ErrCode_ImmutableGlobal: WasmEdge_ErrCode = 78;
+pub const WasmEdge_ErrCode_InvalidResultArity: WasmEdge_ErrCode = 79;
+pub const WasmEdge_ErrCode_MultiTables: WasmEdge_ErrCode = 80;
+pub const WasmEdge_ErrCode_MultiMemories: WasmEdge_ErrCode = 81;
+pub const WasmEdge_ErrCode_InvalidLimit: WasmEdge_ErrCode = 82;
+pub const WasmEdge_ErrCode_InvalidMemPages: WasmEdge_ErrCode = 83;
+pub const WasmEdge_ErrCode_InvalidStartFunc: WasmEdge_ErrCode = 84;
+pub const WasmEdge_ErrCode_InvalidLaneIdx: WasmEdge_ErrCode = 85;
+pub const WasmEdge_ErrCode_ModuleNameConflict: WasmEdge_ErrCode = 96;
+pub const WasmEdge_ErrCode_IncompatibleImportType: WasmEdge_ErrCode = 97;
+pub const WasmEdge_ErrCode_UnknownImport: WasmEdge_ErrCode = 98;
+pub const WasmEdge_ErrCode_DataSegDoesNotFit: WasmEdge_ErrCode = 99;
+pub const WasmEdge_ErrCode_ElemSegDoesNotFit: WasmEdge_ErrCode = 100;
+pub const WasmEdge_ErrCode_WrongInstanceAddress: WasmEdge_ErrCode = 128;
+pub const WasmEdge_ErrCode_WrongInstanceIndex: WasmEdge_ErrCode = 129;
+pub const WasmEdge_ErrCode_InstrTypeMismatch: WasmEdge_ErrCode = 130;
+pub const WasmEdge_ErrCode_FuncSigMismatch: WasmEdge_ErrCode = 131;
+pub const WasmEdge_ErrCode_DivideByZero: WasmEdge_ErrCode = 132;
+pub const WasmEdge_ErrCode_IntegerOverflow: WasmEdge_ErrCode = 133;
+pub const WasmEdge_ErrCode_InvalidConvToInt: WasmEdge_ErrCode = 134;
+pub const WasmEdge_ErrCode_TableOutOfBounds: WasmEdge_ErrCode = 135;
+pub const WasmEdge_ErrCode_MemoryOutOfBounds: WasmEdge_ErrCode = 136;
+pub const WasmEdge_ErrCode_Unreachable: WasmEdge_ErrCode = 137;
+pub const WasmEdge_ErrCode_UninitializedElement: WasmEdge_ErrCode = 138;
+pub const WasmEdge_ErrCode_UndefinedElement: WasmEdge_ErrCode = 139;
+pub const WasmEdge_ErrCode_IndirectCallTypeMismatch: WasmEdge_ErrCode = 140;
+pub const WasmEdge_ErrCode_ExecutionFailed: WasmEdge_ErrCode = 141;
+pub const WasmEdge_ErrCode_RefTypeMismatch: WasmEdge_ErrCode = 142;
+pub type WasmEdge_ValType = ::std::os::raw::c_uint;
+pub const WasmEdge_ValType_I32: WasmEdge_ValType = 127;
+pub const WasmEdge_ValType_I64: WasmEdge_ValType = 126;
+pub const WasmEdge_ValType_F32: WasmEdge_ValType = 125;
+pub const WasmEdge_ValType_F64: WasmEdge_ValType = 124;
+pub const WasmEdge_ValType_V128: WasmEdge_ValType = 123;
+pub const WasmEdge_ValType_FuncRef: WasmEdge_ValType = 112;
+pub const WasmEdge_ValType_ExternRef: WasmEdge_ValType = 111;
+pub type WasmEdge_NumType = ::std::os::raw::c_uint;
+pub const WasmEdge_NumType_I32: WasmEdge_NumType = 127;
+pub const WasmEdge_NumType_I64: WasmEdge_NumType = 126;
+pub const WasmEdge_NumType_F32: WasmEdge_NumType = 125;
+pub const WasmEdge_NumType_F64: WasmEdge_NumType = 124;
+pub const WasmEdge_NumType_V128: WasmEdge_NumType = 123;
+pub type WasmEdge_RefType = ::std::os::raw::c_uint;
+pub const WasmEdge_RefType_FuncRef: WasmEdge_RefType = 112;
+pub const WasmEdge_RefType_ExternRef: WasmEdge_RefType = 111;
+pub type WasmEdge_Mutability = ::std::os::raw::c_uint;
+pub const WasmEdge_Mutability_Const: WasmEdge_Mutability = 0;
+pub const WasmEdge_Mutability_Var: WasmEdge_Mutability = 1;
+pub type WasmEdge_ExternalType = ::std::os::raw::c_uint;
+pub const WasmEdge_ExternalType_Function: WasmEdge_ExternalType = 0;
+pub const WasmEdge_ExternalType_Table: WasmEdge_ExternalType = 1;
+pub const WasmEdge_ExternalType_Memory: WasmEdge_ExternalType = 2;
+pub const WasmEdge_ExternalType_Global: WasmEdge_ExternalType = 3;
+pub type uint128_t = u128;
+pub type int128_t = i128;
+pub const WASMEDGE_VERSION: &[u8; 6usize] = b"0.9.0\0";
+pub const WASMEDGE_VERSION_MAJOR: u32 = 0;
+pub const WASMEDGE_VERSION_MINOR: u32 = 9;
+pub const WASMEDGE_VERSION_PATCH: u32 = 0;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_Value {
+    pub Value: uint128_t,
+    pub Type: WasmEdge_ValType,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_String {
+    pub Length: u32,
+    pub Buf: *const ::std::os::raw::c_char,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_Result {
+    pub Code: u8,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_Limit {
+    pub HasMax: bool,
+    pub Min: u32,
+    pub Max: u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_ConfigureContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_StatisticsContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_ASTModuleContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_FunctionTypeContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_MemoryTypeContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_TableTypeContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_GlobalTypeContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_ImportTypeContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_ExportTypeContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_CompilerContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_LoaderContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_ValidatorContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_ExecutorContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_StoreContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_FunctionInstanceContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_TableInstanceContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_MemoryInstanceContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_GlobalInstanceContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_ImportObjectContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WasmEdge_VMContext {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub fn WasmEdge_VersionGet() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn WasmEdge_VersionGetMajor() -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_VersionGetMinor() -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_VersionGetPatch() -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_LogSetErrorLevel();
+}
+extern "C" {
+    pub fn WasmEdge_LogSetDebugLevel();
+}
+extern "C" {
+    pub fn WasmEdge_ValueGenI32(Val: i32) -> WasmEdge_Value;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGenI64(Val: i64) -> WasmEdge_Value;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGenF32(Val: f32) -> WasmEdge_Value;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGenF64(Val: f64) -> WasmEdge_Value;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGenV128(Val: int128_t) -> WasmEdge_Value;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGenNullRef(T: WasmEdge_RefType) -> WasmEdge_Value;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGenFuncRef(Index: u32) -> WasmEdge_Value;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGenExternRef(Ref: *mut ::std::os::raw::c_void) -> WasmEdge_Value;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGetI32(Val: WasmEdge_Value) -> i32;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGetI64(Val: WasmEdge_Value) -> i64;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGetF32(Val: WasmEdge_Value) -> f32;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGetF64(Val: WasmEdge_Value) -> f64;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGetV128(Val: WasmEdge_Value) -> int128_t;
+}
+extern "C" {
+    pub fn WasmEdge_ValueIsNullRef(Val: WasmEdge_Value) -> bool;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGetFuncIdx(Val: WasmEdge_Value) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_ValueGetExternRef(Val: WasmEdge_Value) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn WasmEdge_StringCreateByCString(Str: *const ::std::os::raw::c_char) -> WasmEdge_String;
+}
+extern "C" {
+    pub fn WasmEdge_StringCreateByBuffer(
+        Buf: *const ::std::os::raw::c_char,
+        Len: u32,
+    ) -> WasmEdge_String;
+}
+extern "C" {
+    pub fn WasmEdge_StringWrap(Buf: *const ::std::os::raw::c_char, Len: u32) -> WasmEdge_String;
+}
+extern "C" {
+    pub fn WasmEdge_StringIsEqual(Str1: WasmEdge_String, Str2: WasmEdge_String) -> bool;
+}
+extern "C" {
+    pub fn WasmEdge_StringCopy(
+        Str: WasmEdge_String,
+        Buf: *mut ::std::os::raw::c_char,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StringDelete(Str: WasmEdge_String);
+}
+extern "C" {
+    pub fn WasmEdge_ResultOK(Res: WasmEdge_Result) -> bool;
+}
+extern "C" {
+    pub fn WasmEdge_ResultGetCode(Res: WasmEdge_Result) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_ResultGetMessage(Res: WasmEdge_Result) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn WasmEdge_LimitIsEqual(Lim1: WasmEdge_Limit, Lim2: WasmEdge_Limit) -> bool;
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureCreate() -> *mut WasmEdge_ConfigureContext;
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureAddProposal(
+        Cxt: *mut WasmEdge_ConfigureContext,
+        Prop: WasmEdge_Proposal,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureRemoveProposal(
+        Cxt: *mut WasmEdge_ConfigureContext,
+        Prop: WasmEdge_Proposal,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureHasProposal(
+        Cxt: *const WasmEdge_ConfigureContext,
+        Prop: WasmEdge_Proposal,
+    ) -> bool;
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureAddHostRegistration(
+        Cxt: *mut WasmEdge_ConfigureContext,
+        Host: WasmEdge_HostRegistration,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureRemoveHostRegistration(
+        Cxt: *mut WasmEdge_ConfigureContext,
+        Host: WasmEdge_HostRegistration,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureHasHostRegistration(
+        Cxt: *const WasmEdge_ConfigureContext,
+        Host: WasmEdge_HostRegistration,
+    ) -> bool;
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureSetMaxMemoryPage(Cxt: *mut WasmEdge_ConfigureContext, Page: u32);
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureGetMaxMemoryPage(Cxt: *const WasmEdge_ConfigureContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureCompilerSetOptimizationLevel(
+        Cxt: *mut WasmEdge_ConfigureContext,
+        Level: WasmEdge_CompilerOptimizationLevel,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureCompilerGetOptimizationLevel(
+        Cxt: *const WasmEdge_ConfigureContext,
+    ) -> WasmEdge_CompilerOptimizationLevel;
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureCompilerSetDumpIR(Cxt: *mut WasmEdge_ConfigureContext, IsDump: bool);
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureCompilerIsDumpIR(Cxt: *const WasmEdge_ConfigureContext) -> bool;
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureStatisticsSetInstructionCounting(
+        Cxt: *mut WasmEdge_ConfigureContext,
+        IsCount: bool,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureStatisticsIsInstructionCounting(
+        Cxt: *const WasmEdge_ConfigureContext,
+    ) -> bool;
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureStatisticsSetTimeMeasuring(
+        Cxt: *mut WasmEdge_ConfigureContext,
+        IsMeasure: bool,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureStatisticsIsTimeMeasuring(
+        Cxt: *const WasmEdge_ConfigureContext,
+    ) -> bool;
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureStatisticsSetCostMeasuring(
+        Cxt: *mut WasmEdge_ConfigureContext,
+        IsMeasure: bool,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureStatisticsIsCostMeasuring(
+        Cxt: *const WasmEdge_ConfigureContext,
+    ) -> bool;
+}
+extern "C" {
+    pub fn WasmEdge_ConfigureDelete(Cxt: *mut WasmEdge_ConfigureContext);
+}
+extern "C" {
+    pub fn WasmEdge_StatisticsCreate() -> *mut WasmEdge_StatisticsContext;
+}
+extern "C" {
+    pub fn WasmEdge_StatisticsGetInstrCount(Cxt: *const WasmEdge_StatisticsContext) -> u64;
+}
+extern "C" {
+    pub fn WasmEdge_StatisticsGetInstrPerSecond(Cxt: *const WasmEdge_StatisticsContext) -> f64;
+}
+extern "C" {
+    pub fn WasmEdge_StatisticsGetTotalCost(Cxt: *const WasmEdge_StatisticsContext) -> u64;
+}
+extern "C" {
+    pub fn WasmEdge_StatisticsSetCostTable(
+        Cxt: *mut WasmEdge_StatisticsContext,
+        CostArr: *mut u64,
+        Len: u32,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_StatisticsSetCostLimit(Cxt: *mut WasmEdge_StatisticsContext, Limit: u64);
+}
+extern "C" {
+    pub fn WasmEdge_StatisticsDelete(Cxt: *mut WasmEdge_StatisticsContext);
+}
+extern "C" {
+    pub fn WasmEdge_ASTModuleListImportsLength(Cxt: *const WasmEdge_ASTModuleContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_ASTModuleListImports(
+        Cxt: *const WasmEdge_ASTModuleContext,
+        Imports: *mut *const WasmEdge_ImportTypeContext,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_ASTModuleListExportsLength(Cxt: *const WasmEdge_ASTModuleContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_ASTModuleListExports(
+        Cxt: *const WasmEdge_ASTModuleContext,
+        Exports: *mut *const WasmEdge_ExportTypeContext,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_ASTModuleDelete(Cxt: *mut WasmEdge_ASTModuleContext);
+}
+extern "C" {
+    pub fn WasmEdge_FunctionTypeCreate(
+        ParamList: *const WasmEdge_ValType,
+        ParamLen: u32,
+        ReturnList: *const WasmEdge_ValType,
+        ReturnLen: u32,
+    ) -> *mut WasmEdge_FunctionTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_FunctionTypeGetParametersLength(
+        Cxt: *const WasmEdge_FunctionTypeContext,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_FunctionTypeGetParameters(
+        Cxt: *const WasmEdge_FunctionTypeContext,
+        List: *mut WasmEdge_ValType,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_FunctionTypeGetReturnsLength(Cxt: *const WasmEdge_FunctionTypeContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_FunctionTypeGetReturns(
+        Cxt: *const WasmEdge_FunctionTypeContext,
+        List: *mut WasmEdge_ValType,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_FunctionTypeDelete(Cxt: *mut WasmEdge_FunctionTypeContext);
+}
+extern "C" {
+    pub fn WasmEdge_TableTypeCreate(
+        RefType: WasmEdge_RefType,
+        Limit: WasmEdge_Limit,
+    ) -> *mut WasmEdge_TableTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_TableTypeGetRefType(Cxt: *const WasmEdge_TableTypeContext) -> WasmEdge_RefType;
+}
+extern "C" {
+    pub fn WasmEdge_TableTypeGetLimit(Cxt: *const WasmEdge_TableTypeContext) -> WasmEdge_Limit;
+}
+extern "C" {
+    pub fn WasmEdge_TableTypeDelete(Cxt: *mut WasmEdge_TableTypeContext);
+}
+extern "C" {
+    pub fn WasmEdge_MemoryTypeCreate(Limit: WasmEdge_Limit) -> *mut WasmEdge_MemoryTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_MemoryTypeGetLimit(Cxt: *const WasmEdge_MemoryTypeContext) -> WasmEdge_Limit;
+}
+extern "C" {
+    pub fn WasmEdge_MemoryTypeDelete(Cxt: *mut WasmEdge_MemoryTypeContext);
+}
+extern "C" {
+    pub fn WasmEdge_GlobalTypeCreate(
+        ValType: WasmEdge_ValType,
+        Mut: WasmEdge_Mutability,
+    ) -> *mut WasmEdge_GlobalTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_GlobalTypeGetValType(
+        Cxt: *const WasmEdge_GlobalTypeContext,
+    ) -> WasmEdge_ValType;
+}
+extern "C" {
+    pub fn WasmEdge_GlobalTypeGetMutability(
+        Cxt: *const WasmEdge_GlobalTypeContext,
+    ) -> WasmEdge_Mutability;
+}
+extern "C" {
+    pub fn WasmEdge_GlobalTypeDelete(Cxt: *mut WasmEdge_GlobalTypeContext);
+}
+extern "C" {
+    pub fn WasmEdge_ImportTypeGetExternalType(
+        Cxt: *const WasmEdge_ImportTypeContext,
+    ) -> WasmEdge_ExternalType;
+}
+extern "C" {
+    pub fn WasmEdge_ImportTypeGetModuleName(
+        Cxt: *const WasmEdge_ImportTypeContext,
+    ) -> WasmEdge_String;
+}
+extern "C" {
+    pub fn WasmEdge_ImportTypeGetExternalName(
+        Cxt: *const WasmEdge_ImportTypeContext,
+    ) -> WasmEdge_String;
+}
+extern "C" {
+    pub fn WasmEdge_ImportTypeGetFunctionType(
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+        Cxt: *const WasmEdge_ImportTypeContext,
+    ) -> *const WasmEdge_FunctionTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_ImportTypeGetTableType(
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+        Cxt: *const WasmEdge_ImportTypeContext,
+    ) -> *const WasmEdge_TableTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_ImportTypeGetMemoryType(
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+        Cxt: *const WasmEdge_ImportTypeContext,
+    ) -> *const WasmEdge_MemoryTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_ImportTypeGetGlobalType(
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+        Cxt: *const WasmEdge_ImportTypeContext,
+    ) -> *const WasmEdge_GlobalTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_ExportTypeGetExternalType(
+        Cxt: *const WasmEdge_ExportTypeContext,
+    ) -> WasmEdge_ExternalType;
+}
+extern "C" {
+    pub fn WasmEdge_ExportTypeGetExternalName(
+        Cxt: *const WasmEdge_ExportTypeContext,
+    ) -> WasmEdge_String;
+}
+extern "C" {
+    pub fn WasmEdge_ExportTypeGetFunctionType(
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+        Cxt: *const WasmEdge_ExportTypeContext,
+    ) -> *const WasmEdge_FunctionTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_ExportTypeGetTableType(
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+        Cxt: *const WasmEdge_ExportTypeContext,
+    ) -> *const WasmEdge_TableTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_ExportTypeGetMemoryType(
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+        Cxt: *const WasmEdge_ExportTypeContext,
+    ) -> *const WasmEdge_MemoryTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_ExportTypeGetGlobalType(
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+        Cxt: *const WasmEdge_ExportTypeContext,
+    ) -> *const WasmEdge_GlobalTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_CompilerCreate(
+        ConfCxt: *const WasmEdge_ConfigureContext,
+    ) -> *mut WasmEdge_CompilerContext;
+}
+extern "C" {
+    pub fn WasmEdge_CompilerCompile(
+        Cxt: *mut WasmEdge_CompilerContext,
+        InPath: *const ::std::os::raw::c_char,
+        OutPath: *const ::std::os::raw::c_char,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_CompilerDelete(Cxt: *mut WasmEdge_CompilerContext);
+}
+extern "C" {
+    pub fn WasmEdge_LoaderCreate(
+        ConfCxt: *const WasmEdge_ConfigureContext,
+    ) -> *mut WasmEdge_LoaderContext;
+}
+extern "C" {
+    pub fn WasmEdge_LoaderParseFromFile(
+        Cxt: *mut WasmEdge_LoaderContext,
+        Module: *mut *mut WasmEdge_ASTModuleContext,
+        Path: *const ::std::os::raw::c_char,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_LoaderParseFromBuffer(
+        Cxt: *mut WasmEdge_LoaderContext,
+        Module: *mut *mut WasmEdge_ASTModuleContext,
+        Buf: *const u8,
+        BufLen: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_LoaderDelete(Cxt: *mut WasmEdge_LoaderContext);
+}
+extern "C" {
+    pub fn WasmEdge_ValidatorCreate(
+        ConfCxt: *const WasmEdge_ConfigureContext,
+    ) -> *mut WasmEdge_ValidatorContext;
+}
+extern "C" {
+    pub fn WasmEdge_ValidatorValidate(
+        Cxt: *mut WasmEdge_ValidatorContext,
+        ModuleCxt: *const WasmEdge_ASTModuleContext,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_ValidatorDelete(Cxt: *mut WasmEdge_ValidatorContext);
+}
+extern "C" {
+    pub fn WasmEdge_ExecutorCreate(
+        ConfCxt: *const WasmEdge_ConfigureContext,
+        StatCxt: *mut WasmEdge_StatisticsContext,
+    ) -> *mut WasmEdge_ExecutorContext;
+}
+extern "C" {
+    pub fn WasmEdge_ExecutorInstantiate(
+        Cxt: *mut WasmEdge_ExecutorContext,
+        StoreCxt: *mut WasmEdge_StoreContext,
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_ExecutorRegisterImport(
+        Cxt: *mut WasmEdge_ExecutorContext,
+        StoreCxt: *mut WasmEdge_StoreContext,
+        ImportCxt: *const WasmEdge_ImportObjectContext,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_ExecutorRegisterModule(
+        Cxt: *mut WasmEdge_ExecutorContext,
+        StoreCxt: *mut WasmEdge_StoreContext,
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+        ModuleName: WasmEdge_String,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_ExecutorInvoke(
+        Cxt: *mut WasmEdge_ExecutorContext,
+        StoreCxt: *mut WasmEdge_StoreContext,
+        FuncName: WasmEdge_String,
+        Params: *const WasmEdge_Value,
+        ParamLen: u32,
+        Returns: *mut WasmEdge_Value,
+        ReturnLen: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_ExecutorInvokeRegistered(
+        Cxt: *mut WasmEdge_ExecutorContext,
+        StoreCxt: *mut WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+        FuncName: WasmEdge_String,
+        Params: *const WasmEdge_Value,
+        ParamLen: u32,
+        Returns: *mut WasmEdge_Value,
+        ReturnLen: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_ExecutorDelete(Cxt: *mut WasmEdge_ExecutorContext);
+}
+extern "C" {
+    pub fn WasmEdge_StoreCreate() -> *mut WasmEdge_StoreContext;
+}
+extern "C" {
+    pub fn WasmEdge_StoreFindFunction(
+        Cxt: *mut WasmEdge_StoreContext,
+        Name: WasmEdge_String,
+    ) -> *mut WasmEdge_FunctionInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_StoreFindFunctionRegistered(
+        Cxt: *mut WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+        FuncName: WasmEdge_String,
+    ) -> *mut WasmEdge_FunctionInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_StoreFindTable(
+        Cxt: *mut WasmEdge_StoreContext,
+        Name: WasmEdge_String,
+    ) -> *mut WasmEdge_TableInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_StoreFindTableRegistered(
+        Cxt: *mut WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+        TableName: WasmEdge_String,
+    ) -> *mut WasmEdge_TableInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_StoreFindMemory(
+        Cxt: *mut WasmEdge_StoreContext,
+        Name: WasmEdge_String,
+    ) -> *mut WasmEdge_MemoryInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_StoreFindMemoryRegistered(
+        Cxt: *mut WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+        MemoryName: WasmEdge_String,
+    ) -> *mut WasmEdge_MemoryInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_StoreFindGlobal(
+        Cxt: *mut WasmEdge_StoreContext,
+        Name: WasmEdge_String,
+    ) -> *mut WasmEdge_GlobalInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_StoreFindGlobalRegistered(
+        Cxt: *mut WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+        GlobalName: WasmEdge_String,
+    ) -> *mut WasmEdge_GlobalInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListFunctionLength(Cxt: *const WasmEdge_StoreContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListFunction(
+        Cxt: *const WasmEdge_StoreContext,
+        Names: *mut WasmEdge_String,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListFunctionRegisteredLength(
+        Cxt: *const WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListFunctionRegistered(
+        Cxt: *const WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+        Names: *mut WasmEdge_String,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListTableLength(Cxt: *const WasmEdge_StoreContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListTable(
+        Cxt: *const WasmEdge_StoreContext,
+        Names: *mut WasmEdge_String,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListTableRegisteredLength(
+        Cxt: *const WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListTableRegistered(
+        Cxt: *const WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+        Names: *mut WasmEdge_String,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListMemoryLength(Cxt: *const WasmEdge_StoreContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListMemory(
+        Cxt: *const WasmEdge_StoreContext,
+        Names: *mut WasmEdge_String,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListMemoryRegisteredLength(
+        Cxt: *const WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListMemoryRegistered(
+        Cxt: *const WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+        Names: *mut WasmEdge_String,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListGlobalLength(Cxt: *const WasmEdge_StoreContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListGlobal(
+        Cxt: *const WasmEdge_StoreContext,
+        Names: *mut WasmEdge_String,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListGlobalRegisteredLength(
+        Cxt: *const WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListGlobalRegistered(
+        Cxt: *const WasmEdge_StoreContext,
+        ModuleName: WasmEdge_String,
+        Names: *mut WasmEdge_String,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListModuleLength(Cxt: *const WasmEdge_StoreContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreListModule(
+        Cxt: *const WasmEdge_StoreContext,
+        Names: *mut WasmEdge_String,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_StoreDelete(Cxt: *mut WasmEdge_StoreContext);
+}
+pub type WasmEdge_HostFunc_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        Data: *mut ::std::os::raw::c_void,
+        MemCxt: *mut WasmEdge_MemoryInstanceContext,
+        Params: *const WasmEdge_Value,
+        Returns: *mut WasmEdge_Value,
+    ) -> WasmEdge_Result,
+>;
+extern "C" {
+    pub fn WasmEdge_FunctionInstanceCreate(
+        Type: *const WasmEdge_FunctionTypeContext,
+        HostFunc: WasmEdge_HostFunc_t,
+        Data: *mut ::std::os::raw::c_void,
+        Cost: u64,
+    ) -> *mut WasmEdge_FunctionInstanceContext;
+}
+pub type WasmEdge_WrapFunc_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        This: *mut ::std::os::raw::c_void,
+        Data: *mut ::std::os::raw::c_void,
+        MemCxt: *mut WasmEdge_MemoryInstanceContext,
+        Params: *const WasmEdge_Value,
+        ParamLen: u32,
+        Returns: *mut WasmEdge_Value,
+        ReturnLen: u32,
+    ) -> WasmEdge_Result,
+>;
+extern "C" {
+    pub fn WasmEdge_FunctionInstanceCreateBinding(
+        Type: *const WasmEdge_FunctionTypeContext,
+        WrapFunc: WasmEdge_WrapFunc_t,
+        Binding: *mut ::std::os::raw::c_void,
+        Data: *mut ::std::os::raw::c_void,
+        Cost: u64,
+    ) -> *mut WasmEdge_FunctionInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_FunctionInstanceGetFunctionType(
+        Cxt: *const WasmEdge_FunctionInstanceContext,
+    ) -> *const WasmEdge_FunctionTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_FunctionInstanceDelete(Cxt: *mut WasmEdge_FunctionInstanceContext);
+}
+extern "C" {
+    pub fn WasmEdge_TableInstanceCreate(
+        TabType: *const WasmEdge_TableTypeContext,
+    ) -> *mut WasmEdge_TableInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_TableInstanceGetTableType(
+        Cxt: *const WasmEdge_TableInstanceContext,
+    ) -> *const WasmEdge_TableTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_TableInstanceGetData(
+        Cxt: *const WasmEdge_TableInstanceContext,
+        Data: *mut WasmEdge_Value,
+        Offset: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_TableInstanceSetData(
+        Cxt: *mut WasmEdge_TableInstanceContext,
+        Data: WasmEdge_Value,
+        Offset: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_TableInstanceGetSize(Cxt: *const WasmEdge_TableInstanceContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_TableInstanceGrow(
+        Cxt: *mut WasmEdge_TableInstanceContext,
+        Size: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_TableInstanceDelete(Cxt: *mut WasmEdge_TableInstanceContext);
+}
+extern "C" {
+    pub fn WasmEdge_MemoryInstanceCreate(
+        MemType: *const WasmEdge_MemoryTypeContext,
+    ) -> *mut WasmEdge_MemoryInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_MemoryInstanceGetMemoryType(
+        Cxt: *const WasmEdge_MemoryInstanceContext,
+    ) -> *const WasmEdge_MemoryTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_MemoryInstanceGetData(
+        Cxt: *const WasmEdge_MemoryInstanceContext,
+        Data: *mut u8,
+        Offset: u32,
+        Length: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_MemoryInstanceSetData(
+        Cxt: *mut WasmEdge_MemoryInstanceContext,
+        Data: *const u8,
+        Offset: u32,
+        Length: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_MemoryInstanceGetPointer(
+        Cxt: *mut WasmEdge_MemoryInstanceContext,
+        Offset: u32,
+        Length: u32,
+    ) -> *mut u8;
+}
+extern "C" {
+    pub fn WasmEdge_MemoryInstanceGetPointerConst(
+        Cxt: *const WasmEdge_MemoryInstanceContext,
+        Offset: u32,
+        Length: u32,
+    ) -> *const u8;
+}
+extern "C" {
+    pub fn WasmEdge_MemoryInstanceGetPageSize(Cxt: *const WasmEdge_MemoryInstanceContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_MemoryInstanceGrowPage(
+        Cxt: *mut WasmEdge_MemoryInstanceContext,
+        Page: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_MemoryInstanceDelete(Cxt: *mut WasmEdge_MemoryInstanceContext);
+}
+extern "C" {
+    pub fn WasmEdge_GlobalInstanceCreate(
+        GlobType: *const WasmEdge_GlobalTypeContext,
+        Value: WasmEdge_Value,
+    ) -> *mut WasmEdge_GlobalInstanceContext;
+}
+extern "C" {
+    pub fn WasmEdge_GlobalInstanceGetGlobalType(
+        Cxt: *const WasmEdge_GlobalInstanceContext,
+    ) -> *const WasmEdge_GlobalTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_GlobalInstanceGetValue(
+        Cxt: *const WasmEdge_GlobalInstanceContext,
+    ) -> WasmEdge_Value;
+}
+extern "C" {
+    pub fn WasmEdge_GlobalInstanceSetValue(
+        Cxt: *mut WasmEdge_GlobalInstanceContext,
+        Value: WasmEdge_Value,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_GlobalInstanceDelete(Cxt: *mut WasmEdge_GlobalInstanceContext);
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectCreate(
+        ModuleName: WasmEdge_String,
+    ) -> *mut WasmEdge_ImportObjectContext;
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectCreateWASI(
+        Args: *const *const ::std::os::raw::c_char,
+        ArgLen: u32,
+        Envs: *const *const ::std::os::raw::c_char,
+        EnvLen: u32,
+        Dirs: *const *const ::std::os::raw::c_char,
+        DirLen: u32,
+        Preopens: *const *const ::std::os::raw::c_char,
+        PreopenLen: u32,
+    ) -> *mut WasmEdge_ImportObjectContext;
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectInitWASI(
+        Cxt: *mut WasmEdge_ImportObjectContext,
+        Args: *const *const ::std::os::raw::c_char,
+        ArgLen: u32,
+        Envs: *const *const ::std::os::raw::c_char,
+        EnvLen: u32,
+        Dirs: *const *const ::std::os::raw::c_char,
+        DirLen: u32,
+        Preopens: *const *const ::std::os::raw::c_char,
+        PreopenLen: u32,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectWasiSetStdio(
+        Cxt: *mut WasmEdge_ImportObjectContext,
+        StdIn: i32,
+        StdOut: i32,
+        StdErr: i32,
+    ) -> bool;
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectWasiGetExitCode(Cxt: *const WasmEdge_ImportObjectContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectCreateWasmEdgeProcess(
+        AllowedCmds: *const *const ::std::os::raw::c_char,
+        CmdsLen: u32,
+        AllowAll: bool,
+    ) -> *mut WasmEdge_ImportObjectContext;
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectInitWasmEdgeProcess(
+        Cxt: *mut WasmEdge_ImportObjectContext,
+        AllowedCmds: *const *const ::std::os::raw::c_char,
+        CmdsLen: u32,
+        AllowAll: bool,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectAddFunction(
+        Cxt: *mut WasmEdge_ImportObjectContext,
+        Name: WasmEdge_String,
+        FuncCxt: *mut WasmEdge_FunctionInstanceContext,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectAddTable(
+        Cxt: *mut WasmEdge_ImportObjectContext,
+        Name: WasmEdge_String,
+        TableCxt: *mut WasmEdge_TableInstanceContext,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectAddMemory(
+        Cxt: *mut WasmEdge_ImportObjectContext,
+        Name: WasmEdge_String,
+        MemoryCxt: *mut WasmEdge_MemoryInstanceContext,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectAddGlobal(
+        Cxt: *mut WasmEdge_ImportObjectContext,
+        Name: WasmEdge_String,
+        GlobalCxt: *mut WasmEdge_GlobalInstanceContext,
+    );
+}
+extern "C" {
+    pub fn WasmEdge_ImportObjectDelete(Cxt: *mut WasmEdge_ImportObjectContext);
+}
+extern "C" {
+    pub fn WasmEdge_VMCreate(
+        ConfCxt: *const WasmEdge_ConfigureContext,
+        StoreCxt: *mut WasmEdge_StoreContext,
+    ) -> *mut WasmEdge_VMContext;
+}
+extern "C" {
+    pub fn WasmEdge_VMRegisterModuleFromFile(
+        Cxt: *mut WasmEdge_VMContext,
+        ModuleName: WasmEdge_String,
+        Path: *const ::std::os::raw::c_char,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMRegisterModuleFromBuffer(
+        Cxt: *mut WasmEdge_VMContext,
+        ModuleName: WasmEdge_String,
+        Buf: *const u8,
+        BufLen: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMRegisterModuleFromImport(
+        Cxt: *mut WasmEdge_VMContext,
+        ImportCxt: *const WasmEdge_ImportObjectContext,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMRegisterModuleFromASTModule(
+        Cxt: *mut WasmEdge_VMContext,
+        ModuleName: WasmEdge_String,
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMRunWasmFromFile(
+        Cxt: *mut WasmEdge_VMContext,
+        Path: *const ::std::os::raw::c_char,
+        FuncName: WasmEdge_String,
+        Params: *const WasmEdge_Value,
+        ParamLen: u32,
+        Returns: *mut WasmEdge_Value,
+        ReturnLen: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMRunWasmFromBuffer(
+        Cxt: *mut WasmEdge_VMContext,
+        Buf: *const u8,
+        BufLen: u32,
+        FuncName: WasmEdge_String,
+        Params: *const WasmEdge_Value,
+        ParamLen: u32,
+        Returns: *mut WasmEdge_Value,
+        ReturnLen: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMRunWasmFromASTModule(
+        Cxt: *mut WasmEdge_VMContext,
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+        FuncName: WasmEdge_String,
+        Params: *const WasmEdge_Value,
+        ParamLen: u32,
+        Returns: *mut WasmEdge_Value,
+        ReturnLen: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMLoadWasmFromFile(
+        Cxt: *mut WasmEdge_VMContext,
+        Path: *const ::std::os::raw::c_char,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMLoadWasmFromBuffer(
+        Cxt: *mut WasmEdge_VMContext,
+        Buf: *const u8,
+        BufLen: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMLoadWasmFromASTModule(
+        Cxt: *mut WasmEdge_VMContext,
+        ASTCxt: *const WasmEdge_ASTModuleContext,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMValidate(Cxt: *mut WasmEdge_VMContext) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMInstantiate(Cxt: *mut WasmEdge_VMContext) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMExecute(
+        Cxt: *mut WasmEdge_VMContext,
+        FuncName: WasmEdge_String,
+        Params: *const WasmEdge_Value,
+        ParamLen: u32,
+        Returns: *mut WasmEdge_Value,
+        ReturnLen: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMExecuteRegistered(
+        Cxt: *mut WasmEdge_VMContext,
+        ModuleName: WasmEdge_String,
+        FuncName: WasmEdge_String,
+        Params: *const WasmEdge_Value,
+        ParamLen: u32,
+        Returns: *mut WasmEdge_Value,
+        ReturnLen: u32,
+    ) -> WasmEdge_Result;
+}
+extern "C" {
+    pub fn WasmEdge_VMGetFunctionType(
+        Cxt: *mut WasmEdge_VMContext,
+        FuncName: WasmEdge_String,
+    ) -> *const WasmEdge_FunctionTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_VMGetFunctionTypeRegistered(
+        Cxt: *mut WasmEdge_VMContext,
+        ModuleName: WasmEdge_String,
+        FuncName: WasmEdge_String,
+    ) -> *const WasmEdge_FunctionTypeContext;
+}
+extern "C" {
+    pub fn WasmEdge_VMCleanup(Cxt: *mut WasmEdge_VMContext);
+}
+extern "C" {
+    pub fn WasmEdge_VMGetFunctionListLength(Cxt: *mut WasmEdge_VMContext) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_VMGetFunctionList(
+        Cxt: *mut WasmEdge_VMContext,
+        Names: *mut WasmEdge_String,
+        FuncTypes: *mut *const WasmEdge_FunctionTypeContext,
+        Len: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn WasmEdge_VMGetImportModuleContext(
+        Cxt: *mut WasmEdge_VMContext,
+        Reg: WasmEdge_HostRegistration,
+    ) -> *mut WasmEdge_ImportObjectContext;
+}
+extern "C" {
+    pub fn WasmEdge_VMGetStoreContext(Cxt: *mut WasmEdge_VMContext) -> *mut WasmEdge_StoreContext;
+}
+extern "C" {
+    pub fn WasmEdge_VMGetStatisticsContext(
+        Cxt: *mut WasmEdge_VMContext,
+    ) -> *mut WasmEdge_StatisticsContext;
+}
+extern "C" {
+    pub fn WasmEdge_VMDelete(Cxt: *mut WasmEdge_VMContext);
+}
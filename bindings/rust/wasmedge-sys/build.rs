@@ -1,107 +1,354 @@
-use std::path::PathBuf;
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
 
 const WASMEDGE_H: &str = "wasmedge.h";
 
+/// The `(major, minor)` versions of WasmEdge whose C API the crate binds, each
+/// with pregenerated bindings in `bindings/`.
+const SUPPORTED_VERSIONS: RangeInclusive<(u32, u32)> = (0, 9)..=(0, 9);
+
+/// The functions used by the crate that were added during the supported
+/// versions, and may be missing from a build of them.
+const REQUIRED_FUNCTIONS: &[&str] = &[
+    "WasmEdge_ImportObjectWasiSetStdio",
+    "WasmEdge_ImportObjectWasiGetExitCode",
+];
+
 fn main() {
-    let Paths {
-        header,
-        lib_dir,
-        inc_dir,
-    } = find_wasmedge().expect("wasmedge header not found");
+    #[cfg(feature = "static")]
+    let paths = build_wasmedge();
+    #[cfg(not(feature = "static"))]
+    let paths = find_wasmedge().unwrap_or_else(|tried| {
+        panic!(
+            "WasmEdge not found, tried:\n  {}\nSet `WASMEDGE_INSTALL_DIR` to the installation \
+             prefix, or `WASMEDGE_INCLUDE_DIR` and `WASMEDGE_LIB_DIR`. `WASMEDGE_DIR` names \
+             the WasmEdge source tree, not an installation.",
+            tried.join("\n  ")
+        )
+    });
 
+    let (major, minor) = check_version(&paths.inc_dir);
+    check_api(&paths.header);
+    let pregenerated = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("bindings")
+        .join(format!("wasmedge_{}_{}.rs", major, minor));
+    // Read by the test checking that the pregenerated bindings are up to date.
+    println!(
+        "cargo:rustc-env=WASMEDGE_PREGENERATED_BINDINGS={}",
+        pregenerated.display()
+    );
     let out_file = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("wasmedge.rs");
+    #[cfg(feature = "bindgen")]
+    generate_bindings(&paths.header, &paths.inc_dir, &out_file);
+    #[cfg(not(feature = "bindgen"))]
+    copy_bindings(&pregenerated, &out_file);
+
+    if cfg!(feature = "static") {
+        println!("cargo:rustc-link-search=native={}", paths.lib_dir.display());
+        println!("cargo:rustc-link-lib=static=wasmedge_c");
+        // The static library does not carry its own dependencies.
+        match std::env::var("CARGO_CFG_TARGET_OS").as_deref() {
+            Ok("macos") => println!("cargo:rustc-link-lib=dylib=c++"),
+            _ => {
+                for lib in ["stdc++", "dl", "pthread", "rt", "m"] {
+                    println!("cargo:rustc-link-lib=dylib={}", lib);
+                }
+            }
+        }
+    } else {
+        println!(
+            "cargo:rustc-env=LD_LIBRARY_PATH={}",
+            paths.lib_dir.display()
+        );
+        println!("cargo:rustc-link-search={}", paths.lib_dir.display());
+        println!("cargo:rustc-link-lib=dylib=wasmedge_c");
+    }
+}
+
+/// Builds the static `libwasmedge_c` from the WasmEdge source tree with CMake,
+/// and returns where it is installed.
+///
+/// The source tree is the one this crate lives in, or `WASMEDGE_DIR` if set.
+/// The AOT compiler is built unless `WASMEDGE_BUILD_AOT_RUNTIME=OFF`, which
+/// drops the dependency on LLVM.
+#[cfg(feature = "static")]
+fn build_wasmedge() -> Paths {
+    println!("cargo:rerun-if-env-changed=WASMEDGE_DIR");
+    println!("cargo:rerun-if-env-changed=WASMEDGE_BUILD_AOT_RUNTIME");
+    let src_dir = match std::env::var_os("WASMEDGE_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../.."),
+    };
+    assert!(
+        src_dir.join("CMakeLists.txt").is_file(),
+        "`{}` is not the WasmEdge source tree, set `WASMEDGE_DIR`",
+        src_dir.display()
+    );
+    for dir in ["CMakeLists.txt", "cmake", "include", "lib", "thirdparty"] {
+        println!("cargo:rerun-if-changed={}", src_dir.join(dir).display());
+    }
+
+    let mut config = cmake::Config::new(&src_dir);
+    config
+        .define("WASMEDGE_BUILD_SHARED_LIB", "OFF")
+        .define("WASMEDGE_BUILD_STATIC_LIB", "ON")
+        .define("WASMEDGE_BUILD_TOOLS", "OFF")
+        .define("WASMEDGE_BUILD_TESTS", "OFF")
+        // Some distributions install to `lib64` by default.
+        .define("CMAKE_INSTALL_LIBDIR", "lib");
+    if let Some(aot) = std::env::var_os("WASMEDGE_BUILD_AOT_RUNTIME") {
+        config.define("WASMEDGE_BUILD_AOT_RUNTIME", aot);
+    }
+    Paths::in_prefix(&config.build())
+}
+
+/// Returns the location of wasmedge.h and libwasmedge_c.(dylib|so), or the
+/// headers that were looked for if none is found.
+///
+/// The candidates are, by priority:
+/// 1. The directories specified by `WASMEDGE_INCLUDE_DIR` and `WASMEDGE_LIB_DIR`,
+///    which must be set together.
+/// 2. The installation prefix specified by `WASMEDGE_INSTALL_DIR`.
+/// 3. The build directory specified by `WASMEDGE_BUILD_DIR`, for in-tree builds.
+/// 4. The `wasmedge` package of pkg-config.
+/// 5. The "XDG" local installation prefix `~/.local`.
+/// 6. The global installation prefixes `/usr/local` and `/usr`.
+/// 7. Backward compatiable the path berfore 0.9
+///
+/// `WASMEDGE_LIB_DIR` overrides the library directory of any candidate.
+/// Unlike in earlier versions of the crate, `WASMEDGE_DIR` is not searched: it
+/// names the source tree, which the tests load their example modules from, so
+/// an installation prefix goes in `WASMEDGE_INSTALL_DIR` instead.
+#[cfg(not(feature = "static"))]
+fn find_wasmedge() -> Result<Paths, Vec<String>> {
+    macro_rules! env_path {
+        ($env_var:literal) => {{
+            println!("cargo:rerun-if-env-changed={}", $env_var);
+            std::env::var_os($env_var).map(PathBuf::from)
+        }};
+    }
+
+    let lib_dir = env_path!("WASMEDGE_LIB_DIR");
+    // A source of candidates that yields none is reported as an error.
+    let mut candidates: Vec<Result<Paths, &str>> = Vec::new();
+
+    if let Some(inc_dir) = env_path!("WASMEDGE_INCLUDE_DIR") {
+        let lib_dir = lib_dir
+            .clone()
+            .expect("`WASMEDGE_INCLUDE_DIR` requires `WASMEDGE_LIB_DIR` to be set too");
+        candidates.push(Ok(Paths {
+            header: inc_dir.join(WASMEDGE_H),
+            lib_dir,
+            inc_dir: inc_dir
+                .parent()
+                .map_or_else(|| inc_dir.clone(), Path::to_path_buf),
+        }));
+    }
+    if let Some(prefix) = env_path!("WASMEDGE_INSTALL_DIR") {
+        candidates.push(Ok(Paths::in_prefix(&prefix)));
+    }
+    if let Some(build_dir) = env_path!("WASMEDGE_BUILD_DIR") {
+        candidates.push(Ok(Paths {
+            header: build_dir.join("include/api/wasmedge").join(WASMEDGE_H),
+            lib_dir: build_dir.join("lib/api"),
+            inc_dir: build_dir.join("include/api"),
+        }));
+    }
+    match pkg_config::Config::new()
+        .cargo_metadata(false)
+        .env_metadata(true)
+        .probe("wasmedge")
+    {
+        Ok(library) => {
+            let lib_dir = library
+                .link_paths
+                .first()
+                .cloned()
+                .unwrap_or_else(|| PathBuf::from("/usr/lib"));
+            candidates.extend(library.include_paths.into_iter().map(|inc_dir| {
+                Ok(Paths {
+                    header: inc_dir.join("wasmedge").join(WASMEDGE_H),
+                    lib_dir: lib_dir.clone(),
+                    inc_dir,
+                })
+            }));
+        }
+        Err(_) => candidates.push(Err("the `wasmedge` package of pkg-config")),
+    }
+    if let Some(home) = env_path!("HOME") {
+        candidates.push(Ok(Paths::in_prefix(&home.join(".local"))));
+    }
+    candidates.push(Ok(Paths::in_prefix(Path::new("/usr/local"))));
+    candidates.push(Ok(Paths::in_prefix(Path::new("/usr"))));
+    // Before 0.9, the header was installed right in the include directory.
+    let mut legacy = Paths::in_prefix(Path::new("/usr"));
+    legacy.header = legacy.inc_dir.join(WASMEDGE_H);
+    candidates.push(Ok(legacy));
+
+    let mut tried = Vec::new();
+    for candidate in candidates {
+        match candidate {
+            Ok(mut paths) if paths.header.is_file() => {
+                if let Some(lib_dir) = lib_dir {
+                    paths.lib_dir = lib_dir;
+                }
+                return Ok(paths);
+            }
+            Ok(paths) => tried.push(format!("`{}`", paths.header.display())),
+            Err(source) => tried.push(source.to_string()),
+        }
+    }
+    Err(tried)
+}
+
+/// Generates the bindings of the WasmEdge C API declared in `header`.
+///
+/// Only the items of the API are kept, without their doc comments, so that the
+/// output only changes with the API and can be compared with the pregenerated
+/// bindings.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(header: &Path, inc_dir: &Path, out_file: &Path) {
     bindgen::builder()
         .header(
             header
                 .to_str()
                 .unwrap_or_else(|| panic!("`{}` must be a utf-8 path", header.display())),
         )
-        .clang_arg(format!("-I{}", inc_dir.as_path().display()))
+        .clang_arg(format!("-I{}", inc_dir.display()))
+        .allowlist_function("WasmEdge_.*")
+        .allowlist_type("WasmEdge_.*")
+        .allowlist_var("WASMEDGE_.*|WasmEdge_.*")
         .prepend_enum_name(false) // The API already prepends the name.
+        .layout_tests(false)
+        .generate_comments(false)
         .dynamic_link_require_all(true)
         .parse_callbacks(Box::new(bindgen::CargoCallbacks))
         .generate()
         .expect("failed to generate bindings")
         .write_to_file(out_file)
         .expect("failed to write bindings");
-
-    println!("cargo:rustc-env=LD_LIBRARY_PATH={}", lib_dir.display());
-    println!("cargo:rustc-link-search={}", lib_dir.display());
-    println!("cargo:rustc-link-lib=dylib=wasmedge_c");
 }
 
-/// Check header and Returns the location of wasmedge.h and libwasmedge_c.(dylib|so)
-/// The priorities are:
-/// 1. The locations specified by `WASMEDGE_INCLUDE_DIR` and `WASMEDGE_LIB_DIR`.
-/// 2. The build directory, if this is an in-tree build.
-/// 3. The "XDG" local installation dirs: `~/.local/include` and `~/.local/lib`.
-/// 4. The global installation dirs: `/usr/include` and `/usr/bin`.
-/// 5. Backward compatiable the path berfore 0.9
-fn find_wasmedge() -> Option<Paths> {
-    macro_rules! env_path {
-        ($env_var:literal) => {
-            std::env::var_os($env_var).map(PathBuf::from)
-        };
+/// Copies the `pregenerated` bindings, which are only valid on the targets where
+/// `uint128_t` is a builtin type.
+#[cfg(not(feature = "bindgen"))]
+fn copy_bindings(pregenerated: &Path, out_file: &Path) {
+    match std::env::var("CARGO_CFG_TARGET_ARCH").as_deref() {
+        Ok("x86_64") | Ok("aarch64") => {}
+        arch => panic!(
+            "there are no pregenerated bindings for the `{}` architecture, enable the `bindgen` \
+             feature of wasmedge-sys",
+            arch.unwrap_or_default()
+        ),
     }
+    println!("cargo:rerun-if-changed={}", pregenerated.display());
+    std::fs::copy(pregenerated, out_file).unwrap_or_else(|err| {
+        panic!(
+            "failed to copy the bindings `{}`: {}",
+            pregenerated.display(),
+            err
+        )
+    });
+}
 
-    let mut inc_dir = env_path!("WASMEDGE_INCLUDE_DIR");
-    let mut lib_dir = env_path!("WASMEDGE_LIB_DIR");
+/// Returns the `(major, minor)` version of the WasmEdge headers in `inc_dir`,
+/// and fails the build unless it is supported, rather than with link errors or
+/// crashes later on.
+///
+/// Builds of a source tree without git tags have no version, and are assumed
+/// to be of the latest supported one, whose API [`check_api`] still checks.
+fn check_version(inc_dir: &Path) -> (u32, u32) {
+    let header = inc_dir.join("wasmedge").join("version.h");
+    println!("cargo:rerun-if-changed={}", header.display());
+    let defines = std::fs::read_to_string(&header)
+        .unwrap_or_else(|err| panic!("failed to read `{}`: {}", header.display(), err));
+    let version = |name: &str| -> Option<u32> {
+        defines.lines().find_map(|line| {
+            let mut tokens = line.split_whitespace();
+            match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some("#define"), Some(define), Some(value)) if define == name => {
+                    value.parse().ok()
+                }
+                _ => None,
+            }
+        })
+    };
+    let (major, minor) = match (
+        version("WASMEDGE_VERSION_MAJOR"),
+        version("WASMEDGE_VERSION_MINOR"),
+    ) {
+        (Some(major), Some(minor)) => (major, minor),
+        _ => panic!(
+            "`{}` does not define WASMEDGE_VERSION_MAJOR and WASMEDGE_VERSION_MINOR",
+            header.display()
+        ),
+    };
 
-    let build_dir = env_path!("WASMEDGE_BUILD_DIR");
-    if let Some((build_dir, found_inc_dir)) = contains_wasmedge_h(build_dir, "include/api/wasmedge")
-    {
-        inc_dir = inc_dir.or(Some(found_inc_dir));
-        lib_dir = lib_dir.or_else(|| Some(build_dir.join("lib/api")));
+    if (major, minor) == (0, 0) {
+        return *SUPPORTED_VERSIONS.end();
     }
-
-    let xdg_dir = env_path!("HOME").map(|d| d.join(".local"));
-    if let Some((xdg_dir, found_inc_dir)) = contains_wasmedge_h(xdg_dir, "include") {
-        inc_dir = inc_dir.or(Some(found_inc_dir));
-        lib_dir = lib_dir.or_else(|| Some(xdg_dir.join("lib")));
+    if !SUPPORTED_VERSIONS.contains(&(major, minor)) {
+        let (min, max) = (SUPPORTED_VERSIONS.start(), SUPPORTED_VERSIONS.end());
+        let supported = if min == max {
+            format!("{}.{}", min.0, min.1)
+        } else {
+            format!("{}.{} to {}.{}", min.0, min.1, max.0, max.1)
+        };
+        panic!(
+            "WasmEdge {}.{} found at `{}` is not supported by wasmedge-sys {}, which requires \
+             WasmEdge {}",
+            major,
+            minor,
+            header.display(),
+            env!("CARGO_PKG_VERSION"),
+            supported
+        );
     }
+    (major, minor)
+}
 
-    let header = inc_dir
-        .clone()
-        .unwrap_or_else(|| PathBuf::from("/usr/include/wasmedge"))
-        .join(WASMEDGE_H);
-
-    if header.exists() {
-        Some(Paths {
-            header,
-            lib_dir: lib_dir.unwrap_or_else(|| PathBuf::from("/usr/lib")),
-            inc_dir: inc_dir
-                .unwrap_or_else(|| PathBuf::from("/usr/include/wasmedge"))
-                .parent()
-                .unwrap()
-                .to_path_buf(),
-        })
-    } else if PathBuf::from("/usr/include").join(WASMEDGE_H).exists() {
-        // check the header path of old version
-        Some(Paths {
-            header: PathBuf::from("/usr/include").join(WASMEDGE_H),
-            lib_dir: PathBuf::from("/usr/lib"),
-            inc_dir: PathBuf::from("/usr/include"),
+/// Fails the build unless `header` declares the `REQUIRED_FUNCTIONS`, which a
+/// supported version may lack if it predates them, e.g. when built from source.
+fn check_api(header: &Path) {
+    println!("cargo:rerun-if-changed={}", header.display());
+    let declarations = std::fs::read_to_string(header)
+        .unwrap_or_else(|err| panic!("failed to read `{}`: {}", header.display(), err));
+    let missing: Vec<_> = REQUIRED_FUNCTIONS
+        .iter()
+        .filter(|name| {
+            !declarations
+                .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .any(|token| token == **name)
         })
-    } else {
-        None
+        .collect();
+    if !missing.is_empty() {
+        panic!(
+            "the WasmEdge found at `{}` lacks the functions {:?} required by wasmedge-sys {}, \
+             update it",
+            header.display(),
+            missing,
+            env!("CARGO_PKG_VERSION")
+        );
     }
 }
 
-/// If the WasmEdge header file is found under `base_dir/inc_subdir`, returns
-/// `Some((base_dir, base_dir/inc_subdir))`.
-fn contains_wasmedge_h(base_dir: Option<PathBuf>, inc_subdir: &str) -> Option<(PathBuf, PathBuf)> {
-    base_dir.and_then(|base_dir| {
-        let inc_dir = base_dir.join(inc_subdir);
-        inc_dir
-            .join(WASMEDGE_H)
-            .is_file()
-            .then(|| (base_dir, inc_dir))
-    })
-}
-
 #[derive(Debug)]
 struct Paths {
     header: PathBuf,
     lib_dir: PathBuf,
     inc_dir: PathBuf,
 }
+
+impl Paths {
+    /// The layout of an installation under `prefix`.
+    fn in_prefix(prefix: &Path) -> Self {
+        let inc_dir = prefix.join("include");
+        Self {
+            header: inc_dir.join("wasmedge").join(WASMEDGE_H),
+            lib_dir: prefix.join("lib"),
+            inc_dir,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use super::wasmedge;
+use crate::{
+    config::Config,
+    raw_result::{decode_result, ErrReport},
+};
+use std::ffi::CString;
+
+/// The AOT compiler, which compiles a Wasm file into a file that WasmEdge
+/// loads as native code. It uses the optimization level and the IR dumping
+/// option of its [`Config`].
+#[derive(Debug)]
+pub struct Compiler {
+    pub(crate) ctx: *mut wasmedge::WasmEdge_CompilerContext,
+}
+
+impl Drop for Compiler {
+    fn drop(&mut self) {
+        unsafe { wasmedge::WasmEdge_CompilerDelete(self.ctx) };
+    }
+}
+
+// SAFETY: a compiler has no affinity to the thread that created it, but keeps
+// state while compiling, so it is not `Sync`.
+unsafe impl Send for Compiler {}
+
+impl Compiler {
+    pub fn create(config: &Config) -> Self {
+        let ctx = unsafe { wasmedge::WasmEdge_CompilerCreate(config.ctx) };
+        assert!(!ctx.is_null(), "failed to create WasmEdge compiler");
+        Self { ctx }
+    }
+
+    /// Compiles the Wasm file `in_path` into `out_path`.
+    ///
+    /// Fails with [`ErrorKind::AOTDisabled`](crate::ErrorKind::AOTDisabled)
+    /// if WasmEdge was built without the AOT runtime.
+    pub fn compile(&self, in_path: CString, out_path: CString) -> Result<(), ErrReport> {
+        decode_result(unsafe {
+            wasmedge::WasmEdge_CompilerCompile(self.ctx, in_path.as_ptr(), out_path.as_ptr())
+        })
+    }
+}
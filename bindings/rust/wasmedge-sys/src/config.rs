@@ -11,6 +11,11 @@ impl Drop for Config {
     }
 }
 
+// SAFETY: a configuration is plain data, which WasmEdge copies into every
+// context created from it and only reads through a shared reference.
+unsafe impl Send for Config {}
+unsafe impl Sync for Config {}
+
 impl Config {
     pub fn new() -> Self {
         Self::default()
@@ -94,6 +99,42 @@ impl Config {
         self
     }
 
+    /// Returns whether VMs created with the config register the WASI module.
+    pub fn is_wasi_enabled(&self) -> bool {
+        unsafe {
+            wasmedge::WasmEdge_ConfigureHasHostRegistration(
+                self.ctx,
+                wasmedge::WasmEdge_HostRegistration_Wasi,
+            )
+        }
+    }
+
+    /// Registers the `wasmedge_process` module in the VMs created with the
+    /// config, which lets guests run host commands. No command is allowed
+    /// until the module is initialized with [`Vm::init_wasmedge_process`].
+    ///
+    /// [`Vm::init_wasmedge_process`]: crate::Vm::init_wasmedge_process
+    pub fn enable_wasmedge_process(self) -> Self {
+        unsafe {
+            wasmedge::WasmEdge_ConfigureAddHostRegistration(
+                self.ctx,
+                wasmedge::WasmEdge_HostRegistration_WasmEdge_Process,
+            )
+        };
+        self
+    }
+
+    /// Returns whether VMs created with the config register the
+    /// `wasmedge_process` module.
+    pub fn is_wasmedge_process_enabled(&self) -> bool {
+        unsafe {
+            wasmedge::WasmEdge_ConfigureHasHostRegistration(
+                self.ctx,
+                wasmedge::WasmEdge_HostRegistration_WasmEdge_Process,
+            )
+        }
+    }
+
     // For AOT compiler
 
     /// Set the optimization level of AOT compiler.
@@ -122,11 +163,22 @@ impl Config {
         self
     }
 
+    /// Enable or disable execution time measuring.
+    pub fn measure_time(self, enable: bool) -> Self {
+        unsafe { wasmedge::WasmEdge_ConfigureStatisticsSetTimeMeasuring(self.ctx, enable) };
+        self
+    }
+
     /// Enable or disable cost cost measuring.
     pub fn measure_costs(self, enable: bool) -> Self {
         unsafe { wasmedge::WasmEdge_ConfigureStatisticsSetCostMeasuring(self.ctx, enable) };
         self
     }
+
+    /// Returns whether cost measuring, and thus cost limits, are enabled.
+    pub fn is_cost_measuring(&self) -> bool {
+        unsafe { wasmedge::WasmEdge_ConfigureStatisticsIsCostMeasuring(self.ctx) }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -152,5 +204,3 @@ pub enum OptLevel {
     /// Optimize for small code size as much as possible.
     Oz = wasmedge::WasmEdge_CompilerOptimizationLevel_Oz,
 }
-
-// # TODO: WasmEdge_HostRegistration
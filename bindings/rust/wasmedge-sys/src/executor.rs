@@ -0,0 +1,279 @@
+use super::wasmedge;
+use crate::{
+    config::Config,
+    import_obj::ImportObject,
+    module::Module,
+    raw_result::{decode_result, ErrReport, ErrorKind},
+    statistics::Statistics,
+    store::Store,
+    string::StringRef,
+    value::Value,
+};
+
+/// Instantiates validated AST modules into stores and invokes the functions
+/// of the stores.
+///
+/// Unlike a [`Vm`](crate::Vm), an executor is not tied to a module or a
+/// store, so the same module can be instantiated into many stores.
+///
+/// # Example
+///
+/// ```ignore
+///     let module = Loader::create(&config).parse_from_buffer(&wasm)?;
+///     Validator::create(&config).validate(&module)?;
+///
+///     let mut executor = Executor::create(&config, None);
+///     let mut store = Store::new();
+///     executor.instantiate(&mut store, &module)?;
+///     let results = executor.invoke(&mut store, "fib", &[Value::I32(5)])?;
+/// ```
+#[derive(Debug)]
+pub struct Executor {
+    pub(crate) ctx: *mut wasmedge::WasmEdge_ExecutorContext,
+    // Dropped after the executor context, which writes to it.
+    statistics: Option<Statistics>,
+}
+
+impl Drop for Executor {
+    fn drop(&mut self) {
+        unsafe { wasmedge::WasmEdge_ExecutorDelete(self.ctx) };
+    }
+}
+
+// SAFETY: an executor has no affinity to the thread that created it: WasmEdge
+// only keeps thread-local state for the duration of a call. Calls mutate the
+// executor, so it is not `Sync`.
+unsafe impl Send for Executor {}
+
+impl Executor {
+    /// Creates an executor recording its executions into `statistics`, if
+    /// any.
+    pub fn create(config: &Config, statistics: Option<Statistics>) -> Self {
+        let stat_ctx = statistics
+            .as_ref()
+            .map_or(std::ptr::null_mut(), |statistics| statistics.ctx);
+        let ctx = unsafe { wasmedge::WasmEdge_ExecutorCreate(config.ctx, stat_ctx) };
+        assert!(!ctx.is_null(), "failed to create WasmEdge executor");
+        Self { ctx, statistics }
+    }
+
+    pub fn statistics(&self) -> Option<&Statistics> {
+        self.statistics.as_ref()
+    }
+
+    pub fn statistics_mut(&mut self) -> Option<&mut Statistics> {
+        self.statistics.as_mut()
+    }
+
+    /// Instantiates `module` as the anonymous module of `store`.
+    pub fn instantiate(&mut self, store: &mut Store, module: &Module) -> Result<(), ErrReport> {
+        decode_result(unsafe {
+            wasmedge::WasmEdge_ExecutorInstantiate(self.ctx, store.ctx, module.ctx)
+        })
+    }
+
+    /// Instantiates `module` into `store` under the name `mod_name`, so that
+    /// the modules instantiated afterwards can import its exports.
+    pub fn register_module(
+        &mut self,
+        store: &mut Store,
+        module: &Module,
+        mod_name: impl AsRef<str>,
+    ) -> Result<(), ErrReport> {
+        let raw_mod_name: wasmedge::WasmEdge_String = StringRef::from(mod_name.as_ref()).into();
+        decode_result(unsafe {
+            wasmedge::WasmEdge_ExecutorRegisterModule(self.ctx, store.ctx, module.ctx, raw_mod_name)
+        })
+    }
+
+    /// Registers the host instances of `import_obj` into `store` under its
+    /// module name.
+    pub fn register_import(
+        &mut self,
+        store: &mut Store,
+        import_obj: ImportObject,
+    ) -> Result<(), ErrReport> {
+        decode_result(unsafe {
+            wasmedge::WasmEdge_ExecutorRegisterImport(self.ctx, store.ctx, import_obj.ctx)
+        })?;
+        store.import_objs.push(import_obj);
+        Ok(())
+    }
+
+    /// Invokes the exported function `func_name` of the anonymous module of
+    /// `store`.
+    pub fn invoke(
+        &mut self,
+        store: &mut Store,
+        func_name: impl AsRef<str>,
+        params: &[Value],
+    ) -> Result<Vec<Value>, ErrReport> {
+        let results = store
+            .find_function(func_name.as_ref())
+            .ok_or(ErrorKind::FuncNotFound)?
+            .ty()
+            .results;
+        let raw_func_name: wasmedge::WasmEdge_String = StringRef::from(func_name.as_ref()).into();
+        let raw_params: Vec<_> = params
+            .iter()
+            .copied()
+            .map(wasmedge::WasmEdge_Value::from)
+            .collect();
+        let mut returns = vec![wasmedge::WasmEdge_Value::from(Value::I32(0)); results.len()];
+
+        unsafe {
+            decode_result(wasmedge::WasmEdge_ExecutorInvoke(
+                self.ctx,
+                store.ctx,
+                raw_func_name,
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+                returns.as_mut_ptr(),
+                results.len() as u32,
+            ))?;
+        }
+        Ok(decode_returns(returns, results))
+    }
+
+    /// Invokes the exported function `func_name` of the module registered in
+    /// `store` as `mod_name`.
+    pub fn invoke_registered(
+        &mut self,
+        store: &mut Store,
+        mod_name: impl AsRef<str>,
+        func_name: impl AsRef<str>,
+        params: &[Value],
+    ) -> Result<Vec<Value>, ErrReport> {
+        let results = store
+            .find_function_registered(mod_name.as_ref(), func_name.as_ref())
+            .ok_or(ErrorKind::FuncNotFound)?
+            .ty()
+            .results;
+        let raw_mod_name: wasmedge::WasmEdge_String = StringRef::from(mod_name.as_ref()).into();
+        let raw_func_name: wasmedge::WasmEdge_String = StringRef::from(func_name.as_ref()).into();
+        let raw_params: Vec<_> = params
+            .iter()
+            .copied()
+            .map(wasmedge::WasmEdge_Value::from)
+            .collect();
+        let mut returns = vec![wasmedge::WasmEdge_Value::from(Value::I32(0)); results.len()];
+
+        unsafe {
+            decode_result(wasmedge::WasmEdge_ExecutorInvokeRegistered(
+                self.ctx,
+                store.ctx,
+                raw_mod_name,
+                raw_func_name,
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+                returns.as_mut_ptr(),
+                results.len() as u32,
+            ))?;
+        }
+        Ok(decode_returns(returns, results))
+    }
+}
+
+// The returned values are not typed, so decode them with the function type.
+fn decode_returns(
+    returns: Vec<wasmedge::WasmEdge_Value>,
+    results: Vec<crate::types::ValType>,
+) -> Vec<Value> {
+    returns
+        .into_iter()
+        .zip(results)
+        .map(|(value, ty)| Value::from_raw(ty, value))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Loader, Validator};
+    use std::ffi::CString;
+
+    #[test]
+    fn instantiates_module_into_many_stores() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Config::default();
+        let module = Loader::create(&config)
+            .parse_from_file(CString::new(path.to_str().unwrap()).unwrap())
+            .unwrap();
+        Validator::create(&config).validate(&module).unwrap();
+
+        let mut executor = Executor::create(&config, None);
+        let mut store_a = Store::new();
+        let mut store_b = Store::new();
+        executor.instantiate(&mut store_a, &module).unwrap();
+        executor.instantiate(&mut store_b, &module).unwrap();
+        // The instances do not refer to the AST module.
+        drop(module);
+
+        assert_eq!(
+            executor
+                .invoke(&mut store_a, "fib", &[Value::I32(5)])
+                .unwrap(),
+            vec![Value::I32(8)]
+        );
+        assert_eq!(
+            executor
+                .invoke(&mut store_b, "fib", &[Value::I32(10)])
+                .unwrap(),
+            vec![Value::I32(89)]
+        );
+        assert_eq!(
+            executor.invoke(&mut store_a, "fac", &[]).unwrap_err().kind,
+            ErrorKind::FuncNotFound
+        );
+    }
+
+    #[test]
+    fn links_registered_modules() {
+        // (module
+        //   (import "math" "fib" (func (param i32) (result i32)))
+        //   (func (export "fib_plus_one") (param i32) (result i32)
+        //     (i32.add (call 0 (local.get 0)) (i32.const 1))))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x06, 0x01, 0x60, 0x01, 0x7f,
+            0x01, 0x7f, 0x02, 0x0c, 0x01, 0x04, 0x6d, 0x61, 0x74, 0x68, 0x03, 0x66, 0x69, 0x62,
+            0x00, 0x00, 0x03, 0x02, 0x01, 0x00, 0x07, 0x10, 0x01, 0x0c, 0x66, 0x69, 0x62, 0x5f,
+            0x70, 0x6c, 0x75, 0x73, 0x5f, 0x6f, 0x6e, 0x65, 0x00, 0x01, 0x0a, 0x0b, 0x01, 0x09,
+            0x00, 0x20, 0x00, 0x10, 0x00, 0x41, 0x01, 0x6a, 0x0b,
+        ];
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Config::default();
+        let loader = Loader::create(&config);
+        let validator = Validator::create(&config);
+        let math = loader
+            .parse_from_file(CString::new(path.to_str().unwrap()).unwrap())
+            .unwrap();
+        validator.validate(&math).unwrap();
+        let main = loader.parse_from_buffer(wasm).unwrap();
+        validator.validate(&main).unwrap();
+
+        let mut executor = Executor::create(&config, Some(Statistics::new()));
+        let mut store = Store::new();
+        assert_eq!(
+            executor.instantiate(&mut store, &main).unwrap_err().kind,
+            ErrorKind::UnknownImport
+        );
+        executor.register_module(&mut store, &math, "math").unwrap();
+        executor.instantiate(&mut store, &main).unwrap();
+
+        assert_eq!(
+            executor
+                .invoke(&mut store, "fib_plus_one", &[Value::I32(5)])
+                .unwrap(),
+            vec![Value::I32(9)]
+        );
+        assert_eq!(
+            executor
+                .invoke_registered(&mut store, "math", "fib", &[Value::I32(5)])
+                .unwrap(),
+            vec![Value::I32(8)]
+        );
+        assert!(executor.statistics().is_some());
+    }
+}
@@ -0,0 +1,161 @@
+use super::wasmedge;
+use crate::{
+    store::Store,
+    types::{FuncType, ValType},
+    value::Value,
+};
+use std::{
+    convert::TryFrom,
+    fmt,
+    marker::PhantomData,
+    os::raw::c_void,
+    panic::{self, AssertUnwindSafe},
+};
+
+const RESULT_SUCCESS: wasmedge::WasmEdge_Result = wasmedge::WasmEdge_Result { Code: 0x00 };
+const RESULT_TERMINATE: wasmedge::WasmEdge_Result = wasmedge::WasmEdge_Result { Code: 0x01 };
+const RESULT_FAIL: wasmedge::WasmEdge_Result = wasmedge::WasmEdge_Result { Code: 0x02 };
+
+/// The reason a host function stops the execution of the calling guest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trap {
+    /// Stop the execution and report success to the caller of the guest.
+    Terminate,
+    /// Abort the execution with a runtime error.
+    Fail,
+}
+
+// `Send` so that the VMs and import objects holding host functions are `Send`.
+type HostFn = dyn Fn(&[Value]) -> Result<Vec<Value>, Trap> + Send;
+
+/// The closure of a host function, passed to WasmEdge as the binding pointer.
+pub(crate) struct HostFunc {
+    results: Vec<ValType>,
+    func: Box<HostFn>,
+}
+
+impl fmt::Debug for HostFunc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HostFunc")
+            .field("results", &self.results)
+            .finish()
+    }
+}
+
+/// A handle to a function instance living in a [`Store`], or a host function
+/// waiting to be added to an [`ImportObject`](crate::ImportObject).
+#[derive(Debug)]
+pub struct Function<'store> {
+    pub(crate) ctx: *mut wasmedge::WasmEdge_FunctionInstanceContext,
+    // Only set for host functions, which own their context until they are moved
+    // into an import object.
+    pub(crate) host_func: Option<Box<HostFunc>>,
+    pub(crate) store: PhantomData<&'store mut Store>,
+}
+
+impl Drop for Function<'_> {
+    fn drop(&mut self) {
+        if self.host_func.is_some() {
+            unsafe { wasmedge::WasmEdge_FunctionInstanceDelete(self.ctx) };
+        }
+    }
+}
+
+impl Function<'static> {
+    /// Creates a host function of type `ty` calling `func`.
+    ///
+    /// The values returned by `func` must match the results of `ty`, otherwise
+    /// the execution fails as if `func` had returned [`Trap::Fail`]. A panic
+    /// in `func` is caught and fails the execution as well.
+    pub fn wrap<F>(ty: &FuncType, func: F) -> Self
+    where
+        F: Fn(&[Value]) -> Result<Vec<Value>, Trap> + Send + 'static,
+    {
+        let mut host_func = Box::new(HostFunc {
+            results: ty.results.clone(),
+            func: Box::new(func),
+        });
+
+        let ty_ctx = ty.to_raw();
+        let ctx = unsafe {
+            wasmedge::WasmEdge_FunctionInstanceCreateBinding(
+                ty_ctx,
+                Some(wrap_host_func),
+                host_func.as_mut() as *mut HostFunc as *mut c_void,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        unsafe { wasmedge::WasmEdge_FunctionTypeDelete(ty_ctx) };
+        assert!(!ctx.is_null(), "failed to create WasmEdge host function");
+
+        Self {
+            ctx,
+            host_func: Some(host_func),
+            store: PhantomData,
+        }
+    }
+}
+
+impl Function<'_> {
+    pub(crate) fn from_raw(ctx: *mut wasmedge::WasmEdge_FunctionInstanceContext) -> Option<Self> {
+        if ctx.is_null() {
+            None
+        } else {
+            Some(Self {
+                ctx,
+                host_func: None,
+                store: PhantomData,
+            })
+        }
+    }
+
+    /// Returns the signature of the function.
+    pub fn ty(&self) -> FuncType {
+        unsafe { FuncType::from_raw(wasmedge::WasmEdge_FunctionInstanceGetFunctionType(self.ctx)) }
+    }
+}
+
+unsafe extern "C" fn wrap_host_func(
+    this: *mut c_void,
+    _data: *mut c_void,
+    _mem: *mut wasmedge::WasmEdge_MemoryInstanceContext,
+    params: *const wasmedge::WasmEdge_Value,
+    param_len: u32,
+    returns: *mut wasmedge::WasmEdge_Value,
+    return_len: u32,
+) -> wasmedge::WasmEdge_Result {
+    let host_func = &*(this as *const HostFunc);
+    let params = if param_len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(params, param_len as usize)
+    };
+
+    // Unwinding into the C++ runtime is undefined behavior.
+    let res = panic::catch_unwind(AssertUnwindSafe(|| {
+        let params = params
+            .iter()
+            .map(|param| Value::try_from(*param))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| Trap::Fail)?;
+        (host_func.func)(&params)
+    }));
+
+    match res {
+        Ok(Ok(values))
+            if values.len() == return_len as usize
+                && values
+                    .iter()
+                    .map(Value::ty)
+                    .eq(host_func.results.iter().copied()) =>
+        {
+            for (i, value) in values.into_iter().enumerate() {
+                *returns.add(i) = value.into();
+            }
+            RESULT_SUCCESS
+        }
+        Ok(Err(Trap::Terminate)) => RESULT_TERMINATE,
+        _ => RESULT_FAIL,
+    }
+}
@@ -0,0 +1,172 @@
+use super::wasmedge;
+use crate::{
+    raw_result::{ErrReport, ErrorKind},
+    store::Store,
+    types::{GlobalType, Mutability},
+    value::Value,
+};
+use std::{convert::TryFrom, marker::PhantomData};
+
+/// A handle to a global instance living in a [`Store`], or a host global
+/// waiting to be added to an [`ImportObject`](crate::ImportObject).
+#[derive(Debug)]
+pub struct Global<'store> {
+    pub(crate) ctx: *mut wasmedge::WasmEdge_GlobalInstanceContext,
+    // Only set for host globals, which own their context until they are moved
+    // into an import object.
+    pub(crate) owned: bool,
+    pub(crate) store: PhantomData<&'store mut Store>,
+}
+
+impl Drop for Global<'_> {
+    fn drop(&mut self) {
+        if self.owned {
+            unsafe { wasmedge::WasmEdge_GlobalInstanceDelete(self.ctx) };
+        }
+    }
+}
+
+impl Global<'static> {
+    /// Creates a host global of type `ty` holding `value`.
+    ///
+    /// Fails with [`ErrorKind::TypeCheckFailed`] if `value` is not of the
+    /// value type of `ty`.
+    pub fn create(ty: GlobalType, value: Value) -> Result<Self, ErrReport> {
+        if value.ty() != ty.ty {
+            return Err(ErrorKind::TypeCheckFailed.into());
+        }
+
+        let ty_ctx = ty.to_raw();
+        let ctx = unsafe { wasmedge::WasmEdge_GlobalInstanceCreate(ty_ctx, value.into()) };
+        unsafe { wasmedge::WasmEdge_GlobalTypeDelete(ty_ctx) };
+        assert!(!ctx.is_null(), "failed to create WasmEdge global");
+
+        Ok(Self {
+            ctx,
+            owned: true,
+            store: PhantomData,
+        })
+    }
+}
+
+impl Global<'_> {
+    pub(crate) fn from_raw(ctx: *mut wasmedge::WasmEdge_GlobalInstanceContext) -> Option<Self> {
+        if ctx.is_null() {
+            None
+        } else {
+            Some(Self {
+                ctx,
+                owned: false,
+                store: PhantomData,
+            })
+        }
+    }
+
+    /// Returns the type of the global.
+    pub fn ty(&self) -> GlobalType {
+        unsafe { GlobalType::from_raw(wasmedge::WasmEdge_GlobalInstanceGetGlobalType(self.ctx)) }
+    }
+
+    /// Returns the current value of the global.
+    pub fn value(&self) -> Value {
+        let value = unsafe { wasmedge::WasmEdge_GlobalInstanceGetValue(self.ctx) };
+        Value::try_from(value).expect("WasmEdge returned a global of unknown type")
+    }
+
+    /// Sets the value of the global.
+    ///
+    /// Fails with [`ErrorKind::ImmutableGlobal`] if the global is
+    /// [`Mutability::Const`], and with [`ErrorKind::TypeCheckFailed`] if
+    /// `value` is not of the value type of the global.
+    pub fn set(&mut self, value: Value) -> Result<(), ErrReport> {
+        let ty = self.ty();
+        if ty.mutability == Mutability::Const {
+            return Err(ErrorKind::ImmutableGlobal.into());
+        }
+        if value.ty() != ty.ty {
+            return Err(ErrorKind::TypeCheckFailed.into());
+        }
+
+        unsafe { wasmedge::WasmEdge_GlobalInstanceSetValue(self.ctx, value.into()) };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{types::ValType, Config, ImportObject, Vm};
+
+    #[test]
+    fn accesses_exported_and_imported_globals() {
+        // (module
+        //   (import "host" "tenant" (global i64))
+        //   (global (export "version") i32 (i32.const 3))
+        //   (global (export "counter") (mut i32) (i32.const 0))
+        //   (func (export "tenant") (result i64) (global.get 0))
+        //   (func (export "tick") (result i32)
+        //     (global.set 2 (i32.add (global.get 2) (i32.const 1)))
+        //     (global.get 2)))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x09, 0x02, 0x60, 0x00, 0x01,
+            0x7e, 0x60, 0x00, 0x01, 0x7f, 0x02, 0x10, 0x01, 0x04, 0x68, 0x6f, 0x73, 0x74, 0x06,
+            0x74, 0x65, 0x6e, 0x61, 0x6e, 0x74, 0x03, 0x7e, 0x00, 0x03, 0x03, 0x02, 0x00, 0x01,
+            0x06, 0x0b, 0x02, 0x7f, 0x00, 0x41, 0x03, 0x0b, 0x7f, 0x01, 0x41, 0x00, 0x0b, 0x07,
+            0x25, 0x04, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x03, 0x01, 0x07, 0x63,
+            0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x03, 0x02, 0x06, 0x74, 0x65, 0x6e, 0x61, 0x6e,
+            0x74, 0x00, 0x00, 0x04, 0x74, 0x69, 0x63, 0x6b, 0x00, 0x01, 0x0a, 0x12, 0x02, 0x04,
+            0x00, 0x23, 0x00, 0x0b, 0x0b, 0x00, 0x23, 0x02, 0x41, 0x01, 0x6a, 0x24, 0x02, 0x23,
+            0x02, 0x0b,
+        ];
+
+        let tenant_ty = GlobalType::new(ValType::I64, Mutability::Const);
+        assert_eq!(
+            Global::create(tenant_ty, Value::I32(7)).unwrap_err().kind,
+            ErrorKind::TypeCheckFailed
+        );
+        let mut import_obj = ImportObject::create("host");
+        import_obj.add_global("tenant", Global::create(tenant_ty, Value::I64(7)).unwrap());
+
+        let config = Config::default();
+        let vm = Vm::create(&config, None)
+            .register_module_from_import(import_obj)
+            .unwrap();
+        let mut vm = vm
+            .load_wasm_from_bytes(wasm)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .instantiate()
+            .unwrap();
+        assert_eq!(vm.run("tenant", &[]).unwrap(), vec![Value::I64(7)]);
+        assert_eq!(vm.run("tick", &[]).unwrap(), vec![Value::I32(1)]);
+
+        let store = vm.store_mut();
+        let tenant = store.find_global_registered("host", "tenant").unwrap();
+        assert_eq!(tenant.ty(), tenant_ty);
+        assert_eq!(tenant.value(), Value::I64(7));
+        drop(tenant);
+
+        let mut version = store.find_global("version").unwrap();
+        assert_eq!(
+            version.ty(),
+            GlobalType::new(ValType::I32, Mutability::Const)
+        );
+        assert_eq!(version.value(), Value::I32(3));
+        assert_eq!(
+            version.set(Value::I32(4)).unwrap_err().kind,
+            ErrorKind::ImmutableGlobal
+        );
+        assert_eq!(version.value(), Value::I32(3));
+        drop(version);
+
+        let mut counter = store.find_global("counter").unwrap();
+        assert_eq!(
+            counter.set(Value::I64(41)).unwrap_err().kind,
+            ErrorKind::TypeCheckFailed
+        );
+        counter.set(Value::I32(41)).unwrap();
+        drop(counter);
+        assert_eq!(vm.run("tick", &[]).unwrap(), vec![Value::I32(42)]);
+    }
+}
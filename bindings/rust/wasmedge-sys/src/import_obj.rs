@@ -0,0 +1,186 @@
+use super::wasmedge;
+use crate::{
+    function::{Function, HostFunc},
+    global::Global,
+    string::StringRef,
+    table::Table,
+};
+
+/// A named module of host instances that guests can import from.
+#[derive(Debug)]
+pub struct ImportObject {
+    pub(crate) ctx: *mut wasmedge::WasmEdge_ImportObjectContext,
+    // The closures of the host functions, which must outlive the function
+    // instances moved into the context. Boxed so that their address is stable.
+    #[allow(clippy::vec_box)]
+    host_funcs: Vec<Box<HostFunc>>,
+}
+
+impl Drop for ImportObject {
+    fn drop(&mut self) {
+        unsafe { wasmedge::WasmEdge_ImportObjectDelete(self.ctx) };
+    }
+}
+
+// SAFETY: the host instances are only reachable through the import object, and
+// the closures of the host functions are `Send`.
+unsafe impl Send for ImportObject {}
+
+impl ImportObject {
+    pub fn create(module_name: impl AsRef<str>) -> Self {
+        let raw_name: wasmedge::WasmEdge_String = StringRef::from(module_name.as_ref()).into();
+        let ctx = unsafe { wasmedge::WasmEdge_ImportObjectCreate(raw_name) };
+        assert!(!ctx.is_null(), "failed to create WasmEdge import object");
+        Self {
+            ctx,
+            host_funcs: Vec::new(),
+        }
+    }
+
+    /// Adds the host function `func` under `name`.
+    ///
+    /// # Panics
+    ///
+    /// If `func` is not a host function created by [`Function::wrap`].
+    pub fn add_function(&mut self, name: impl AsRef<str>, mut func: Function<'static>) {
+        let host_func = func
+            .host_func
+            .take()
+            .expect("only host functions can be added to an import object");
+        let raw_name: wasmedge::WasmEdge_String = StringRef::from(name.as_ref()).into();
+        unsafe { wasmedge::WasmEdge_ImportObjectAddFunction(self.ctx, raw_name, func.ctx) };
+        self.host_funcs.push(host_func);
+    }
+
+    /// Adds the host table `table` under `name`.
+    ///
+    /// # Panics
+    ///
+    /// If `table` is not a host table created by [`Table::create`].
+    pub fn add_table(&mut self, name: impl AsRef<str>, mut table: Table<'static>) {
+        assert!(
+            table.owned,
+            "only host tables can be added to an import object"
+        );
+        let raw_name: wasmedge::WasmEdge_String = StringRef::from(name.as_ref()).into();
+        unsafe { wasmedge::WasmEdge_ImportObjectAddTable(self.ctx, raw_name, table.ctx) };
+        table.owned = false;
+    }
+
+    /// Adds the host global `global` under `name`.
+    ///
+    /// # Panics
+    ///
+    /// If `global` is not a host global created by [`Global::create`].
+    pub fn add_global(&mut self, name: impl AsRef<str>, mut global: Global<'static>) {
+        assert!(
+            global.owned,
+            "only host globals can be added to an import object"
+        );
+        let raw_name: wasmedge::WasmEdge_String = StringRef::from(name.as_ref()).into();
+        unsafe { wasmedge::WasmEdge_ImportObjectAddGlobal(self.ctx, raw_name, global.ctx) };
+        global.owned = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, ErrorKind, FuncType, Limit, RefType, TableType, Trap, ValType, Value, Vm};
+
+    #[test]
+    fn calls_host_function_from_guest() {
+        // (module
+        //   (import "host" "add" (func $add (param i32 i32) (result i32)))
+        //   (func (export "add_one") (param i32) (result i32)
+        //     (call $add (local.get 0) (i32.const 1))))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x0c, 0x02, 0x60, 0x02, 0x7f,
+            0x7f, 0x01, 0x7f, 0x60, 0x01, 0x7f, 0x01, 0x7f, 0x02, 0x0c, 0x01, 0x04, 0x68, 0x6f,
+            0x73, 0x74, 0x03, 0x61, 0x64, 0x64, 0x00, 0x00, 0x03, 0x02, 0x01, 0x01, 0x07, 0x0b,
+            0x01, 0x07, 0x61, 0x64, 0x64, 0x5f, 0x6f, 0x6e, 0x65, 0x00, 0x01, 0x0a, 0x0a, 0x01,
+            0x08, 0x00, 0x20, 0x00, 0x41, 0x01, 0x10, 0x00, 0x0b,
+        ];
+
+        let mut import_obj = ImportObject::create("host");
+        let ty = FuncType::new([ValType::I32, ValType::I32], [ValType::I32]);
+        import_obj.add_function(
+            "add",
+            Function::wrap(&ty, |params| match params {
+                [Value::I32(a), Value::I32(b)] => Ok(vec![Value::I32(a + b)]),
+                _ => Err(Trap::Fail),
+            }),
+        );
+
+        let config = Config::default();
+        let vm = Vm::create(&config, None)
+            .register_module_from_import(import_obj)
+            .unwrap();
+        let mut vm = vm
+            .load_wasm_from_bytes(wasm)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .instantiate()
+            .unwrap();
+
+        assert_eq!(
+            vm.run("add_one", &[Value::I32(41)]).unwrap(),
+            vec![Value::I32(42)]
+        );
+        assert!(vm.store().module_names().contains(&"host".to_string()));
+    }
+
+    #[test]
+    fn provides_host_table_to_guest() {
+        // (module
+        //   (type (func (result i32)))
+        //   (import "host" "tab" (table 1 2 funcref))
+        //   (func (export "call") (param i32) (result i32)
+        //     (call_indirect (type 0) (local.get 0))))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x0a, 0x02, 0x60, 0x00, 0x01,
+            0x7f, 0x60, 0x01, 0x7f, 0x01, 0x7f, 0x02, 0x0f, 0x01, 0x04, 0x68, 0x6f, 0x73, 0x74,
+            0x03, 0x74, 0x61, 0x62, 0x01, 0x70, 0x01, 0x01, 0x02, 0x03, 0x02, 0x01, 0x01, 0x07,
+            0x08, 0x01, 0x04, 0x63, 0x61, 0x6c, 0x6c, 0x00, 0x00, 0x0a, 0x09, 0x01, 0x07, 0x00,
+            0x20, 0x00, 0x11, 0x00, 0x00, 0x0b,
+        ];
+
+        let mut import_obj = ImportObject::create("host");
+        import_obj.add_table(
+            "tab",
+            Table::create(TableType::new(RefType::FuncRef, Limit::new(1, Some(2)))),
+        );
+
+        let config = Config::default();
+        let vm = Vm::create(&config, None)
+            .register_module_from_import(import_obj)
+            .unwrap();
+        let mut vm = vm
+            .load_wasm_from_bytes(wasm)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .instantiate()
+            .unwrap();
+        assert_eq!(vm.store().table_names_registered("host"), vec!["tab"]);
+
+        // The guest calls through the null element of the host table.
+        assert_eq!(
+            vm.run("call", &[Value::I32(0)]).unwrap_err().kind,
+            ErrorKind::UninitializedElement
+        );
+        assert_eq!(
+            vm.run("call", &[Value::I32(1)]).unwrap_err().kind,
+            ErrorKind::UndefinedElement
+        );
+
+        let mut table = vm.store_mut().find_table_registered("host", "tab").unwrap();
+        table.grow(1).unwrap();
+        drop(table);
+        assert_eq!(
+            vm.run("call", &[Value::I32(1)]).unwrap_err().kind,
+            ErrorKind::UninitializedElement
+        );
+    }
+}
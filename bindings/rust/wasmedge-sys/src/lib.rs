@@ -1,3 +1,21 @@
+//! # Thread safety
+//!
+//! [`Config`] and [`Module`] are never modified once created, so they are
+//! `Send` and `Sync` and can be shared between threads, e.g. with an `Arc`,
+//! to build a VM per thread from one loaded module.
+//!
+//! [`Vm`], [`Executor`], [`Store`], [`Statistics`], [`ImportObject`],
+//! [`Loader`], [`Validator`] and [`Compiler`] are `Send` but not `Sync`: they
+//! can be moved to another thread, e.g. a worker of a thread pool, but are
+//! mutated by every call and must not be used from two threads at once. For
+//! this reason, host functions must be `Send`.
+//!
+//! The handles borrowed from a store, such as [`Function`] or [`Memory`], are
+//! neither `Send` nor `Sync`.
+//!
+//! An [`InterruptHandle`] is `Send` and `Sync`, to stop an execution running
+//! on another thread.
+
 #![deny(rust_2018_idioms, unreachable_pub)]
 
 #[allow(warnings)]
@@ -5,20 +23,46 @@ pub mod wasmedge {
     include!(concat!(env!("OUT_DIR"), "/wasmedge.rs"));
 }
 
+pub mod compiler;
 pub mod config;
+pub mod executor;
+pub mod function;
+pub mod global;
+pub mod import_obj;
+pub mod loader;
+pub mod memory;
 pub mod module;
 pub mod raw_result;
+pub mod statistics;
+pub mod store;
 pub mod string;
+pub mod table;
+pub mod types;
+pub mod validator;
 pub mod value;
 pub mod version;
 pub mod vm;
-pub mod wasi;
 
+pub use compiler::Compiler;
 pub use config::{Config, OptLevel};
+pub use executor::Executor;
+pub use function::{Function, Trap};
+pub use global::Global;
+pub use import_obj::ImportObject;
+pub use loader::Loader;
+pub use memory::{Memory, PAGE_SIZE};
 pub use module::Module;
-pub use raw_result::ErrReport;
+pub use raw_result::{ErrReport, ErrorKind};
+pub use statistics::{InterruptHandle, Statistics};
+pub use store::Store;
 pub use string::{StringBuf, StringRef, WasmEdgeString};
-pub use value::Value;
+pub use table::Table;
+pub use types::{
+    ExportType, ExternType, FuncType, GlobalType, ImportType, Limit, MemoryType, Mutability,
+    RefType, TableType, ValType,
+};
+pub use validator::Validator;
+pub use value::{ExternRef, UnknownValType, Value};
 pub use version::{full_version, semv_version};
 pub use vm::Vm;
 
@@ -37,4 +81,47 @@ mod tests {
             );
         }
     }
+
+    /// Regenerates the pregenerated bindings with
+    /// `WASMEDGE_UPDATE_BINDINGS=1 cargo test -p wasmedge-sys --features bindgen`.
+    #[test]
+    #[cfg(feature = "bindgen")]
+    fn pregenerated_bindings_are_up_to_date() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/wasmedge.rs"));
+        let path = env!("WASMEDGE_PREGENERATED_BINDINGS");
+        if std::env::var_os("WASMEDGE_UPDATE_BINDINGS").is_some() {
+            std::fs::write(path, generated).unwrap();
+            return;
+        }
+        let pregenerated = std::fs::read_to_string(path).unwrap_or_default();
+
+        // Compares the items regardless of their order and formatting, which
+        // depend on the versions of bindgen, libclang and rustfmt.
+        let items = |bindings: &str| {
+            bindings
+                .lines()
+                .filter(|line| !line.starts_with("/*"))
+                .collect::<String>()
+                .split_inclusive(&[';', '}'][..])
+                .map(|item| item.split_whitespace().collect::<String>())
+                .filter(|item| !item.is_empty() && item != "}")
+                .collect::<Vec<_>>()
+        };
+        let (generated, pregenerated) = (items(generated), items(&pregenerated));
+        let missing: Vec<_> = generated
+            .iter()
+            .filter(|item| !pregenerated.contains(item))
+            .collect();
+        let stale: Vec<_> = pregenerated
+            .iter()
+            .filter(|item| !generated.contains(item))
+            .collect();
+        assert!(
+            missing.is_empty() && stale.is_empty(),
+            "`{}` is out of date, missing {:#?}, stale {:#?}",
+            path,
+            missing,
+            stale
+        );
+    }
 }
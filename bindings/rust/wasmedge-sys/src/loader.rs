@@ -0,0 +1,62 @@
+use super::wasmedge;
+use crate::{
+    config::Config,
+    module::Module,
+    raw_result::{decode_result, ErrReport},
+};
+use std::ffi::CString;
+
+/// Parses Wasm binaries into AST modules.
+#[derive(Debug)]
+pub struct Loader {
+    pub(crate) ctx: *mut wasmedge::WasmEdge_LoaderContext,
+}
+
+impl Drop for Loader {
+    fn drop(&mut self) {
+        unsafe { wasmedge::WasmEdge_LoaderDelete(self.ctx) };
+    }
+}
+
+// SAFETY: a loader has no affinity to the thread that created it, but keeps
+// state while parsing, so it is not `Sync`.
+unsafe impl Send for Loader {}
+
+impl Loader {
+    pub fn create(config: &Config) -> Self {
+        let ctx = unsafe { wasmedge::WasmEdge_LoaderCreate(config.ctx) };
+        assert!(!ctx.is_null(), "failed to create WasmEdge loader");
+        Self { ctx }
+    }
+
+    /// Loads a module from the Wasm file `path`.
+    pub fn parse_from_file(&self, path: CString) -> Result<Module, ErrReport> {
+        let mut ctx: *mut wasmedge::WasmEdge_ASTModuleContext = std::ptr::null_mut();
+        let res = unsafe {
+            wasmedge::WasmEdge_LoaderParseFromFile(self.ctx, &mut ctx as *mut _, path.as_ptr())
+        };
+        decode_result(res)?;
+
+        assert!(!ctx.is_null(), "WasmEdge failed to load from file!");
+
+        Ok(Module { ctx })
+    }
+
+    /// Loads a module from the bytes of a Wasm binary.
+    pub fn parse_from_buffer(&self, buffer: &[u8]) -> Result<Module, ErrReport> {
+        let mut ctx: *mut wasmedge::WasmEdge_ASTModuleContext = std::ptr::null_mut();
+        let res = unsafe {
+            wasmedge::WasmEdge_LoaderParseFromBuffer(
+                self.ctx,
+                &mut ctx as *mut _,
+                buffer.as_ptr(),
+                buffer.len() as u32,
+            )
+        };
+        decode_result(res)?;
+
+        assert!(!ctx.is_null(), "WasmEdge failed to load from buffer!");
+
+        Ok(Module { ctx })
+    }
+}
@@ -0,0 +1,171 @@
+use super::wasmedge;
+use crate::{
+    raw_result::{decode_result, ErrReport, ErrorKind},
+    store::Store,
+    types::MemoryType,
+};
+use std::{convert::TryFrom, marker::PhantomData};
+
+/// The size of a page of linear memory.
+pub const PAGE_SIZE: usize = 65536;
+
+/// A handle to a linear memory instance living in a [`Store`].
+///
+/// The memory can be accessed either by copying bytes with [`Memory::read`]
+/// and [`Memory::write`], or in place through the slices returned by
+/// [`Memory::data`] and [`Memory::data_mut`], which borrow the store.
+#[derive(Debug)]
+pub struct Memory<'store> {
+    pub(crate) ctx: *mut wasmedge::WasmEdge_MemoryInstanceContext,
+    pub(crate) store: PhantomData<&'store mut Store>,
+}
+
+impl Memory<'_> {
+    pub(crate) fn from_raw(ctx: *mut wasmedge::WasmEdge_MemoryInstanceContext) -> Option<Self> {
+        if ctx.is_null() {
+            None
+        } else {
+            Some(Self {
+                ctx,
+                store: PhantomData,
+            })
+        }
+    }
+
+    /// Returns the type of the memory, whose limits are the ones it was
+    /// created with.
+    pub fn ty(&self) -> MemoryType {
+        unsafe { MemoryType::from_raw(wasmedge::WasmEdge_MemoryInstanceGetMemoryType(self.ctx)) }
+    }
+
+    /// Returns the size of the memory in pages of 64 KiB.
+    pub fn page_size(&self) -> u32 {
+        unsafe { wasmedge::WasmEdge_MemoryInstanceGetPageSize(self.ctx) }
+    }
+
+    /// Returns the size of the memory in bytes.
+    pub fn data_size(&self) -> usize {
+        self.page_size() as usize * PAGE_SIZE
+    }
+
+    /// Grows the memory by `pages` pages of 64 KiB.
+    pub fn grow(&mut self, pages: u32) -> Result<(), ErrReport> {
+        unsafe { decode_result(wasmedge::WasmEdge_MemoryInstanceGrowPage(self.ctx, pages)) }
+    }
+
+    /// Copies `buf.len()` bytes starting at `offset` into `buf`.
+    ///
+    /// Fails with [`ErrorKind::MemoryOutOfBounds`] if the bytes are not all in
+    /// the memory, including when `buf` is 4 GiB or larger.
+    pub fn read(&self, offset: u32, buf: &mut [u8]) -> Result<(), ErrReport> {
+        let len = u32::try_from(buf.len()).map_err(|_| ErrorKind::MemoryOutOfBounds)?;
+        unsafe {
+            decode_result(wasmedge::WasmEdge_MemoryInstanceGetData(
+                self.ctx,
+                buf.as_mut_ptr(),
+                offset,
+                len,
+            ))
+        }
+    }
+
+    /// Copies `data` into the memory starting at `offset`.
+    ///
+    /// Fails with [`ErrorKind::MemoryOutOfBounds`] if the bytes do not all fit
+    /// in the memory, including when `data` is 4 GiB or larger.
+    pub fn write(&mut self, offset: u32, data: &[u8]) -> Result<(), ErrReport> {
+        let len = u32::try_from(data.len()).map_err(|_| ErrorKind::MemoryOutOfBounds)?;
+        unsafe {
+            decode_result(wasmedge::WasmEdge_MemoryInstanceSetData(
+                self.ctx,
+                data.as_ptr(),
+                offset,
+                len,
+            ))
+        }
+    }
+
+    /// Returns the whole memory as a slice.
+    pub fn data(&self) -> &[u8] {
+        let len = self.data_size();
+        if len == 0 {
+            return &[];
+        }
+        unsafe {
+            let ptr = wasmedge::WasmEdge_MemoryInstanceGetPointerConst(self.ctx, 0, 0);
+            std::slice::from_raw_parts(ptr, len)
+        }
+    }
+
+    /// Returns the whole memory as a mutable slice.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        let len = self.data_size();
+        if len == 0 {
+            return &mut [];
+        }
+        unsafe {
+            let ptr = wasmedge::WasmEdge_MemoryInstanceGetPointer(self.ctx, 0, 0);
+            std::slice::from_raw_parts_mut(ptr, len)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{types::Limit, Config, Value, Vm};
+
+    #[test]
+    fn accesses_exported_memory() {
+        // (module
+        //   (memory (export "mem") 1 2)
+        //   (data (i32.const 16) "hello")
+        //   (func (export "load8") (param i32) (result i32)
+        //     (i32.load8_u (local.get 0))))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x06, 0x01, 0x60, 0x01, 0x7f,
+            0x01, 0x7f, 0x03, 0x02, 0x01, 0x00, 0x05, 0x04, 0x01, 0x01, 0x01, 0x02, 0x07, 0x0f,
+            0x02, 0x03, 0x6d, 0x65, 0x6d, 0x02, 0x00, 0x05, 0x6c, 0x6f, 0x61, 0x64, 0x38, 0x00,
+            0x00, 0x0a, 0x09, 0x01, 0x07, 0x00, 0x20, 0x00, 0x2d, 0x00, 0x00, 0x0b, 0x0b, 0x0b,
+            0x01, 0x00, 0x41, 0x10, 0x0b, 0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f,
+        ];
+
+        let config = Config::default();
+        let vm = Vm::create(&config, None);
+        let mut vm = vm
+            .load_wasm_from_bytes(wasm)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .instantiate()
+            .unwrap();
+
+        {
+            let mut mem = vm.store_mut().find_memory("mem").unwrap();
+            assert_eq!(mem.ty(), MemoryType::new(Limit::new(1, Some(2))));
+            assert_eq!(mem.page_size(), 1);
+
+            let mut buf = [0; 5];
+            mem.read(16, &mut buf).unwrap();
+            assert_eq!(&buf, b"hello");
+            assert_eq!(&mem.data()[16..21], b"hello");
+            assert!(mem.read(PAGE_SIZE as u32 - 2, &mut buf).is_err());
+
+            mem.write(32, b"wasm").unwrap();
+            mem.data_mut()[36] = b'!';
+
+            mem.grow(1).unwrap();
+            assert_eq!(mem.data_size(), 2 * PAGE_SIZE);
+            assert!(mem.grow(1).is_err());
+        }
+
+        assert_eq!(
+            vm.run("load8", &[Value::I32(36)]).unwrap(),
+            vec![Value::I32(b'!' as i32)]
+        );
+        assert_eq!(
+            vm.run("load8", &[Value::I32(32)]).unwrap(),
+            vec![Value::I32(b'w' as i32)]
+        );
+    }
+}
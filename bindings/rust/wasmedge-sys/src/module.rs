@@ -1,5 +1,10 @@
 use super::wasmedge;
-use crate::raw_result::{decode_result, ErrReport};
+use crate::{
+    loader::Loader,
+    raw_result::ErrReport,
+    string::StringRef,
+    types::{ExportType, ExternType, FuncType, GlobalType, ImportType, MemoryType, TableType},
+};
 use std::ffi::CString;
 
 #[derive(Debug)]
@@ -13,21 +18,167 @@ impl Drop for Module {
     }
 }
 
+// SAFETY: an AST module is never modified after it is loaded: validating,
+// instantiating or registering it only reads it, and the VM copies it.
+unsafe impl Send for Module {}
+unsafe impl Sync for Module {}
+
 impl Module {
     pub fn load_from_file(
         config: &crate::config::Config,
         path: CString,
     ) -> Result<Self, ErrReport> {
-        let loader_ctx = unsafe { wasmedge::WasmEdge_LoaderCreate(config.ctx) };
-        let mut ctx: *mut wasmedge::WasmEdge_ASTModuleContext = std::ptr::null_mut();
+        Loader::create(config).parse_from_file(path)
+    }
 
-        let res = unsafe {
-            wasmedge::WasmEdge_LoaderParseFromFile(loader_ctx, &mut ctx as *mut _, path.as_ptr())
-        };
-        decode_result(res)?;
+    /// Loads a module from the bytes of a Wasm binary.
+    pub fn load_from_buffer(
+        config: &crate::config::Config,
+        buffer: &[u8],
+    ) -> Result<Self, ErrReport> {
+        Loader::create(config).parse_from_buffer(buffer)
+    }
 
-        assert!(!ctx.is_null(), "WasmEdge failed to load from file!");
+    /// Returns the imports of the module, in the order of its import section.
+    pub fn imports(&self) -> impl ExactSizeIterator<Item = ImportType> + '_ {
+        let len = unsafe { wasmedge::WasmEdge_ASTModuleListImportsLength(self.ctx) };
+        let mut imports = vec![std::ptr::null(); len as usize];
+        unsafe { wasmedge::WasmEdge_ASTModuleListImports(self.ctx, imports.as_mut_ptr(), len) };
+
+        imports.into_iter().map(move |import| {
+            let ty = match unsafe { wasmedge::WasmEdge_ImportTypeGetExternalType(import) } {
+                wasmedge::WasmEdge_ExternalType_Function => ExternType::Func(unsafe {
+                    FuncType::from_raw(wasmedge::WasmEdge_ImportTypeGetFunctionType(
+                        self.ctx, import,
+                    ))
+                }),
+                wasmedge::WasmEdge_ExternalType_Table => ExternType::Table(unsafe {
+                    TableType::from_raw(wasmedge::WasmEdge_ImportTypeGetTableType(self.ctx, import))
+                }),
+                wasmedge::WasmEdge_ExternalType_Memory => ExternType::Memory(unsafe {
+                    MemoryType::from_raw(wasmedge::WasmEdge_ImportTypeGetMemoryType(
+                        self.ctx, import,
+                    ))
+                }),
+                wasmedge::WasmEdge_ExternalType_Global => ExternType::Global(unsafe {
+                    GlobalType::from_raw(wasmedge::WasmEdge_ImportTypeGetGlobalType(
+                        self.ctx, import,
+                    ))
+                }),
+                raw => panic!("unknown WasmEdge external type {}", raw),
+            };
+            ImportType {
+                module: StringRef::from_raw(unsafe {
+                    wasmedge::WasmEdge_ImportTypeGetModuleName(import)
+                })
+                .into(),
+                name: StringRef::from_raw(unsafe {
+                    wasmedge::WasmEdge_ImportTypeGetExternalName(import)
+                })
+                .into(),
+                ty,
+            }
+        })
+    }
 
-        Ok(Self { ctx })
+    /// Returns the exports of the module, in the order of its export section.
+    pub fn exports(&self) -> impl ExactSizeIterator<Item = ExportType> + '_ {
+        let len = unsafe { wasmedge::WasmEdge_ASTModuleListExportsLength(self.ctx) };
+        let mut exports = vec![std::ptr::null(); len as usize];
+        unsafe { wasmedge::WasmEdge_ASTModuleListExports(self.ctx, exports.as_mut_ptr(), len) };
+
+        exports.into_iter().map(move |export| {
+            let ty = match unsafe { wasmedge::WasmEdge_ExportTypeGetExternalType(export) } {
+                wasmedge::WasmEdge_ExternalType_Function => ExternType::Func(unsafe {
+                    FuncType::from_raw(wasmedge::WasmEdge_ExportTypeGetFunctionType(
+                        self.ctx, export,
+                    ))
+                }),
+                wasmedge::WasmEdge_ExternalType_Table => ExternType::Table(unsafe {
+                    TableType::from_raw(wasmedge::WasmEdge_ExportTypeGetTableType(self.ctx, export))
+                }),
+                wasmedge::WasmEdge_ExternalType_Memory => ExternType::Memory(unsafe {
+                    MemoryType::from_raw(wasmedge::WasmEdge_ExportTypeGetMemoryType(
+                        self.ctx, export,
+                    ))
+                }),
+                wasmedge::WasmEdge_ExternalType_Global => ExternType::Global(unsafe {
+                    GlobalType::from_raw(wasmedge::WasmEdge_ExportTypeGetGlobalType(
+                        self.ctx, export,
+                    ))
+                }),
+                raw => panic!("unknown WasmEdge external type {}", raw),
+            };
+            ExportType {
+                name: StringRef::from_raw(unsafe {
+                    wasmedge::WasmEdge_ExportTypeGetExternalName(export)
+                })
+                .into(),
+                ty,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, Limit, Mutability, RefType, ValType};
+
+    #[test]
+    fn lists_imports_and_exports() {
+        // (module
+        //   (import "env" "log" (func (param i32)))
+        //   (import "env" "table" (table 1 funcref))
+        //   (import "env" "memory" (memory 1 2))
+        //   (import "env" "counter" (global (mut i64)))
+        //   (func (export "answer") (result f32) (f32.const 42))
+        //   (export "memory" (memory 0)))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x09, 0x02, 0x60, 0x01, 0x7f,
+            0x00, 0x60, 0x00, 0x01, 0x7d, 0x02, 0x37, 0x04, 0x03, 0x65, 0x6e, 0x76, 0x03, 0x6c,
+            0x6f, 0x67, 0x00, 0x00, 0x03, 0x65, 0x6e, 0x76, 0x05, 0x74, 0x61, 0x62, 0x6c, 0x65,
+            0x01, 0x70, 0x00, 0x01, 0x03, 0x65, 0x6e, 0x76, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72,
+            0x79, 0x02, 0x01, 0x01, 0x02, 0x03, 0x65, 0x6e, 0x76, 0x07, 0x63, 0x6f, 0x75, 0x6e,
+            0x74, 0x65, 0x72, 0x03, 0x7e, 0x01, 0x03, 0x02, 0x01, 0x01, 0x07, 0x13, 0x02, 0x06,
+            0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x00, 0x01, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72,
+            0x79, 0x02, 0x00, 0x0a, 0x09, 0x01, 0x07, 0x00, 0x43, 0x00, 0x00, 0x28, 0x42, 0x0b,
+        ];
+        let module = Module::load_from_buffer(&Config::default(), wasm).unwrap();
+
+        let memory_ty = ExternType::Memory(MemoryType::new(Limit::new(1, Some(2))));
+        let import = |name: &str, ty| ImportType {
+            module: "env".to_string(),
+            name: name.to_string(),
+            ty,
+        };
+        assert_eq!(
+            module.imports().collect::<Vec<_>>(),
+            vec![
+                import("log", ExternType::Func(FuncType::new([ValType::I32], []))),
+                import(
+                    "table",
+                    ExternType::Table(TableType::new(RefType::FuncRef, Limit::new(1, None)))
+                ),
+                import("memory", memory_ty.clone()),
+                import(
+                    "counter",
+                    ExternType::Global(GlobalType::new(ValType::I64, Mutability::Var))
+                ),
+            ]
+        );
+        assert_eq!(
+            module.exports().collect::<Vec<_>>(),
+            vec![
+                ExportType {
+                    name: "answer".to_string(),
+                    ty: ExternType::Func(FuncType::new([], [ValType::F32])),
+                },
+                ExportType {
+                    name: "memory".to_string(),
+                    ty: memory_ty,
+                },
+            ]
+        );
     }
 }
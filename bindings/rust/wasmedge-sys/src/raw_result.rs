@@ -1,11 +1,211 @@
 use super::wasmedge;
+use std::fmt;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ErrReport {
     pub code: u32,
+    pub kind: ErrorKind,
     pub message: &'static str,
 }
 
+/// Code Generate for `ErrorKind` from the `WasmEdge_ErrCode` enum of
+/// `include/common/enum_errcode.h` :
+///
+/// ```ignore
+/// #[non_exhaustive]
+/// pub enum ErrorKind {
+///     Success,
+///     // ...
+///     Unknown(u32),
+/// }
+///
+/// impl ErrorKind {
+///     pub fn from_code(code: u32) -> Self {
+///         match code {
+///             wasmedge::WasmEdge_ErrCode_Success => Self::Success,
+///             // ...
+///             _ => Self::Unknown(code),
+///         }
+///     }
+///
+///     pub fn code(&self) -> u32 { /* ... */ }
+///
+///     fn message(&self) -> &'static str { /* ... */ }
+/// }
+/// ```
+macro_rules! impl_error_kind {
+    ($( $(#[$doc:meta])* $name:ident => $message:literal ),+ $(,)?) => {
+        paste::paste! {
+            /// The kind of an error reported by WasmEdge.
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            #[non_exhaustive]
+            pub enum ErrorKind {
+                $(
+                    $(#[$doc])*
+                    $name,
+                )+
+                /// A code unknown to this version of the bindings.
+                Unknown(u32),
+            }
+
+            impl ErrorKind {
+                pub fn from_code(code: u32) -> Self {
+                    match code {
+                        $(wasmedge::[<WasmEdge_ErrCode_ $name>] => Self::$name,)+
+                        _ => Self::Unknown(code),
+                    }
+                }
+
+                pub fn code(&self) -> u32 {
+                    match self {
+                        $(Self::$name => wasmedge::[<WasmEdge_ErrCode_ $name>],)+
+                        Self::Unknown(code) => *code,
+                    }
+                }
+
+                fn message(&self) -> &'static str {
+                    match self {
+                        $(Self::$name => $message,)+
+                        Self::Unknown(_) => "unknown error",
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_error_kind! {
+    // WasmEdge runtime
+    Success => "success",
+    Terminated => "terminated",
+    RuntimeError => "generic runtime error",
+    CostLimitExceeded => "cost limit exceeded",
+    WrongVMWorkflow => "wrong VM workflow",
+    FuncNotFound => "wasm function not found",
+    AOTDisabled => "AOT runtime is disabled in this build",
+    // Load phase
+    InvalidPath => "invalid path",
+    ReadError => "read error",
+    UnexpectedEnd => "unexpected end",
+    InvalidMagic => "magic header not detected",
+    InvalidVersion => "unknown binary version",
+    InvalidSection => "malformed section id",
+    SectionSizeMismatch => "section size mismatch",
+    NameSizeOutOfBounds => "length out of bounds",
+    JunkSection => "junk after last section",
+    IncompatibleFuncCode => "function and code section have inconsistent lengths",
+    IncompatibleDataCount => "data count and data section have inconsistent lengths",
+    DataCountRequired => "data count section required",
+    InvalidImportKind => "malformed import kind",
+    InvalidExportKind => "malformed export kind",
+    ExpectedZeroByte => "zero byte expected",
+    InvalidMut => "malformed mutability",
+    TooManyLocals => "too many locals",
+    InvalidValType => "malformed value type",
+    InvalidElemType => "malformed element type",
+    InvalidRefType => "malformed reference type",
+    InvalidUTF8 => "malformed UTF-8 encoding",
+    IntegerTooLarge => "integer too large",
+    IntegerTooLong => "integer representation too long",
+    InvalidOpCode => "illegal opcode",
+    InvalidGrammar => "invalid wasm grammar",
+    // Validation phase
+    InvalidAlignment => "alignment must not be larger than natural",
+    TypeCheckFailed => "type mismatch",
+    InvalidLabelIdx => "unknown label",
+    InvalidLocalIdx => "unknown local",
+    InvalidFuncTypeIdx => "unknown type",
+    InvalidFuncIdx => "unknown function",
+    InvalidTableIdx => "unknown table",
+    InvalidMemoryIdx => "unknown memory",
+    InvalidGlobalIdx => "unknown global",
+    InvalidElemIdx => "unknown elem segment",
+    InvalidDataIdx => "unknown data segment",
+    InvalidRefIdx => "undeclared function reference",
+    ConstExprRequired => "constant expression required",
+    DupExportName => "duplicate export name",
+    ImmutableGlobal => "global is immutable",
+    InvalidResultArity => "invalid result arity",
+    MultiTables => "multiple tables",
+    MultiMemories => "multiple memories",
+    InvalidLimit => "size minimum must not be greater than maximum",
+    InvalidMemPages => "memory size must be at most 65536 pages (4GiB)",
+    InvalidStartFunc => "start function",
+    InvalidLaneIdx => "invalid lane index",
+    // Instantiation phase
+    ModuleNameConflict => "module name conflict",
+    IncompatibleImportType => "incompatible import type",
+    UnknownImport => "unknown import",
+    DataSegDoesNotFit => "data segment does not fit",
+    ElemSegDoesNotFit => "elements segment does not fit",
+    // Execution phase
+    WrongInstanceAddress => "wrong instance address",
+    WrongInstanceIndex => "wrong instance index",
+    InstrTypeMismatch => "instruction type mismatch",
+    FuncSigMismatch => "function signature mismatch",
+    DivideByZero => "integer divide by zero",
+    IntegerOverflow => "integer overflow",
+    InvalidConvToInt => "invalid conversion to integer",
+    TableOutOfBounds => "out of bounds table access",
+    MemoryOutOfBounds => "out of bounds memory access",
+    Unreachable => "unreachable",
+    UninitializedElement => "uninitialized element",
+    UndefinedElement => "undefined element",
+    IndirectCallTypeMismatch => "indirect call type mismatch",
+    ExecutionFailed => "host function failed",
+    RefTypeMismatch => "reference type mismatch",
+}
+
+impl ErrorKind {
+    /// Returns `true` if the guest trapped during the execution, including
+    /// when it ran out of its cost limit.
+    ///
+    /// Errors of the engine or of host functions raised while executing, such
+    /// as [`ErrorKind::FuncSigMismatch`] or [`ErrorKind::ExecutionFailed`], are
+    /// not traps. WasmEdge 0.9 has no code for an exhausted call stack, so a
+    /// guest recursing too deeply is not reported as a trap either.
+    pub fn is_trap(&self) -> bool {
+        matches!(
+            self,
+            Self::Unreachable
+                | Self::MemoryOutOfBounds
+                | Self::TableOutOfBounds
+                | Self::UninitializedElement
+                | Self::UndefinedElement
+                | Self::IndirectCallTypeMismatch
+                | Self::DivideByZero
+                | Self::IntegerOverflow
+                | Self::InvalidConvToInt
+                | Self::CostLimitExceeded
+        )
+    }
+
+    /// Returns `true` if the module could not be read or decoded.
+    pub fn is_load_error(&self) -> bool {
+        matches!(self.code(), 0x20..=0x3F)
+    }
+
+    /// Returns `true` if the module was decoded but is not valid.
+    pub fn is_validation_error(&self) -> bool {
+        matches!(self.code(), 0x40..=0x5F)
+    }
+
+    /// Returns `true` if the module could not be instantiated, e.g. because
+    /// of missing imports.
+    pub fn is_instantiation_error(&self) -> bool {
+        matches!(self.code(), 0x60..=0x7F)
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(code) => write!(f, "unknown error (code {:#04x})", code),
+            _ => f.write_str(self.message()),
+        }
+    }
+}
+
 pub fn is_ok(res: wasmedge::WasmEdge_Result) -> bool {
     unsafe { wasmedge::WasmEdge_ResultOK(res) }
 }
@@ -24,15 +224,25 @@ pub fn get_message<'a>(res: wasmedge::WasmEdge_Result) -> &'a str {
 
 impl From<wasmedge::WasmEdge_Result> for ErrReport {
     fn from(raw_result: wasmedge::WasmEdge_Result) -> Self {
+        let code = get_code(raw_result);
         ErrReport {
-            code: get_code(raw_result),
+            code,
+            kind: ErrorKind::from_code(code),
             message: get_message(raw_result),
         }
     }
 }
 
-// Since WasmEdge_ErrCode is subject to change on the wasmedge side
-// it does not correspond to enum here
+impl From<ErrorKind> for ErrReport {
+    fn from(kind: ErrorKind) -> Self {
+        ErrReport {
+            code: kind.code(),
+            kind,
+            message: kind.message(),
+        }
+    }
+}
+
 pub fn decode_result(raw_result: wasmedge::WasmEdge_Result) -> Result<(), ErrReport> {
     if is_ok(raw_result) {
         Ok(())
@@ -40,3 +250,30 @@ pub fn decode_result(raw_result: wasmedge::WasmEdge_Result) -> Result<(), ErrRep
         Err(raw_result.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_codes_to_kinds() {
+        assert_eq!(ErrorKind::from_code(0x89), ErrorKind::Unreachable);
+        assert_eq!(ErrorKind::MemoryOutOfBounds.code(), 0x88);
+        assert_eq!(ErrorKind::from_code(0xFFFF), ErrorKind::Unknown(0xFFFF));
+
+        assert!(ErrorKind::Unreachable.is_trap());
+        assert!(ErrorKind::CostLimitExceeded.is_trap());
+        assert!(!ErrorKind::InvalidMagic.is_trap());
+        assert!(!ErrorKind::FuncSigMismatch.is_trap());
+        assert!(!ErrorKind::ExecutionFailed.is_trap());
+        assert!(ErrorKind::InvalidMagic.is_load_error());
+        assert!(ErrorKind::TypeCheckFailed.is_validation_error());
+        assert!(ErrorKind::UnknownImport.is_instantiation_error());
+
+        assert_eq!(ErrorKind::IntegerOverflow.to_string(), "integer overflow");
+        assert_eq!(
+            ErrorKind::Unknown(0xFFFF).to_string(),
+            "unknown error (code 0xffff)"
+        );
+    }
+}
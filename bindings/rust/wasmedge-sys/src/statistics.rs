@@ -0,0 +1,263 @@
+use super::wasmedge;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex, MutexGuard,
+};
+
+/// The instruction count, execution time and instruction costs measured
+/// during the execution, as enabled by the [`Config`](crate::Config).
+#[derive(Debug)]
+pub struct Statistics {
+    pub(crate) ctx: *mut wasmedge::WasmEdge_StatisticsContext,
+    // The statistics context returned by `WasmEdge_VMGetStatisticsContext` is
+    // owned by the VM context and must not be deleted here.
+    pub(crate) owned: bool,
+    // The cost limit set by the user, restored once an interrupt is handled.
+    cost_limit: u64,
+    interrupt: Arc<Interrupt>,
+}
+
+/// The state shared with the [`InterruptHandle`]s of a [`Statistics`].
+#[derive(Debug)]
+struct Interrupt {
+    // Null once the statistics context is deleted.
+    ctx: Mutex<StatisticsPtr>,
+    requested: AtomicBool,
+}
+
+#[derive(Debug)]
+struct StatisticsPtr(*mut wasmedge::WasmEdge_StatisticsContext);
+
+// SAFETY: the pointer is only used to set the cost limit, which is safe from
+// any thread because `Statistics::CostLimit` is a `std::atomic` (see
+// include/common/statistics.h), while the lock guarantees the context is alive.
+unsafe impl Send for StatisticsPtr {}
+
+impl Interrupt {
+    fn new(ctx: *mut wasmedge::WasmEdge_StatisticsContext) -> Arc<Self> {
+        Arc::new(Self {
+            ctx: Mutex::new(StatisticsPtr(ctx)),
+            requested: AtomicBool::new(false),
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, StatisticsPtr> {
+        // The lock is never held across a panic.
+        self.ctx.lock().unwrap()
+    }
+}
+
+impl Drop for Statistics {
+    fn drop(&mut self) {
+        self.detach();
+        if self.owned {
+            unsafe { wasmedge::WasmEdge_StatisticsDelete(self.ctx) };
+        }
+    }
+}
+
+// SAFETY: the counters are only written by the execution, which requires
+// exclusive access to the VM or executor owning them.
+unsafe impl Send for Statistics {}
+
+impl Statistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn from_vm(ctx: *mut wasmedge::WasmEdge_StatisticsContext) -> Self {
+        assert!(!ctx.is_null(), "WasmEdge VM has no statistics context");
+        Self {
+            ctx,
+            owned: false,
+            cost_limit: u64::MAX,
+            interrupt: Interrupt::new(ctx),
+        }
+    }
+
+    /// Disconnects the interrupt handles, before the context is deleted.
+    pub(crate) fn detach(&self) {
+        self.interrupt.lock().0 = std::ptr::null_mut();
+    }
+
+    /// Returns the number of instructions executed, if instruction counting
+    /// is enabled.
+    pub fn instr_count(&self) -> u64 {
+        unsafe { wasmedge::WasmEdge_StatisticsGetInstrCount(self.ctx) }
+    }
+
+    /// Returns the number of instructions executed per second, if both
+    /// instruction counting and time measuring are enabled.
+    pub fn instr_per_second(&self) -> f64 {
+        unsafe { wasmedge::WasmEdge_StatisticsGetInstrPerSecond(self.ctx) }
+    }
+
+    /// Returns the total cost of the executed instructions, if cost measuring
+    /// is enabled.
+    pub fn total_cost(&self) -> u64 {
+        unsafe { wasmedge::WasmEdge_StatisticsGetTotalCost(self.ctx) }
+    }
+
+    /// Returns the cost limit, [`u64::MAX`] unless set.
+    pub fn cost_limit(&self) -> u64 {
+        self.cost_limit
+    }
+
+    /// Sets the cost above which the execution fails with
+    /// [`ErrorKind::CostLimitExceeded`](crate::ErrorKind::CostLimitExceeded).
+    pub fn set_cost_limit(&mut self, limit: u64) {
+        self.cost_limit = limit;
+        let _ctx = self.interrupt.lock();
+        // A pending interrupt keeps the limit at zero until it is taken.
+        if !self.interrupt.requested.load(Ordering::SeqCst) {
+            unsafe { wasmedge::WasmEdge_StatisticsSetCostLimit(self.ctx, limit) };
+        }
+    }
+
+    /// Sets the cost of each instruction, indexed by opcode. Instructions
+    /// missing from a shorter table cost nothing.
+    pub fn set_cost_table(&mut self, costs: &[u64]) {
+        // The table is copied, never written through the pointer.
+        unsafe {
+            wasmedge::WasmEdge_StatisticsSetCostTable(
+                self.ctx,
+                costs.as_ptr() as *mut u64,
+                costs.len() as u32,
+            )
+        };
+    }
+
+    /// Returns a handle to interrupt the executions measured by these
+    /// statistics from another thread.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        InterruptHandle {
+            interrupt: Arc::clone(&self.interrupt),
+        }
+    }
+
+    /// Clears the interrupt requested through an [`InterruptHandle`] and
+    /// restores the cost limit. Returns whether an interrupt was requested.
+    pub fn take_interrupt(&mut self) -> bool {
+        let _ctx = self.interrupt.lock();
+        let requested = self.interrupt.requested.swap(false, Ordering::SeqCst);
+        if requested {
+            unsafe { wasmedge::WasmEdge_StatisticsSetCostLimit(self.ctx, self.cost_limit) };
+        }
+        requested
+    }
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        let ctx = unsafe { wasmedge::WasmEdge_StatisticsCreate() };
+        assert!(!ctx.is_null(), "failed to create WasmEdge statistics");
+        Self {
+            ctx,
+            owned: true,
+            cost_limit: u64::MAX,
+            interrupt: Interrupt::new(ctx),
+        }
+    }
+}
+
+/// A handle to stop, from any thread, the executions measured by a
+/// [`Statistics`].
+///
+/// An interrupt lowers the cost limit to zero, so it requires cost measuring
+/// to be enabled in the [`Config`](crate::Config), and the execution fails with
+/// [`ErrorKind::CostLimitExceeded`](crate::ErrorKind::CostLimitExceeded) at the
+/// next instruction. Use [`Statistics::take_interrupt`] to tell it apart from
+/// an exceeded limit.
+///
+/// Only the interpreter checks the cost limit between instructions: code
+/// compiled ahead of time by the [`Compiler`](crate::Compiler) checks it only
+/// when calling a host function, so a loop in such a module is never
+/// interrupted.
+#[derive(Clone, Debug)]
+pub struct InterruptHandle {
+    interrupt: Arc<Interrupt>,
+}
+
+impl InterruptHandle {
+    /// Stops the execution in progress, or the next one if none is running.
+    ///
+    /// Does nothing once the statistics are dropped.
+    pub fn interrupt(&self) {
+        let ctx = self.interrupt.lock();
+        if !ctx.0.is_null() {
+            self.interrupt.requested.store(true, Ordering::SeqCst);
+            unsafe { wasmedge::WasmEdge_StatisticsSetCostLimit(ctx.0, 0) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Config, ErrorKind, Module, Value, Vm};
+    use std::ffi::CString;
+
+    #[test]
+    fn counts_instructions_and_enforces_cost_limit() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Config::default()
+            .count_instructions(true)
+            .measure_costs(true);
+        let module =
+            Module::load_from_file(&config, CString::new(path.to_str().unwrap()).unwrap()).unwrap();
+        let mut vm = Vm::create(&config, None)
+            .load_wasm_from_ast_module(&module)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .instantiate()
+            .unwrap();
+
+        vm.run("fib", &[Value::I32(10)]).unwrap();
+        let stats = vm.statistics();
+        assert!(stats.instr_count() > 0);
+        assert!(stats.total_cost() > 0);
+
+        let stats = vm.statistics_mut();
+        stats.set_cost_table(&[2; 256]);
+        stats.set_cost_limit(stats.total_cost() + 100);
+        let err = vm.run("fib", &[Value::I32(10)]).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::CostLimitExceeded);
+    }
+
+    #[test]
+    fn interrupts_execution_from_another_thread() {
+        // (module (func (export "spin") (loop (br 0))))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x04, 0x01, 0x60, 0x00, 0x00,
+            0x03, 0x02, 0x01, 0x00, 0x07, 0x08, 0x01, 0x04, 0x73, 0x70, 0x69, 0x6e, 0x00, 0x00,
+            0x0a, 0x09, 0x01, 0x07, 0x00, 0x03, 0x40, 0x0c, 0x00, 0x0b, 0x0b,
+        ];
+        let config = Config::default().measure_costs(true);
+        let module = Module::load_from_buffer(&config, wasm).unwrap();
+        let mut vm = Vm::create(&config, None)
+            .load_wasm_from_ast_module(&module)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .instantiate()
+            .unwrap();
+
+        let handle = vm.statistics().interrupt_handle();
+        let interrupter = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            handle.interrupt();
+        });
+        let err = vm.run("spin", &[]).unwrap_err();
+        interrupter.join().unwrap();
+        assert_eq!(err.kind, ErrorKind::CostLimitExceeded);
+        assert!(vm.statistics_mut().take_interrupt());
+        assert!(!vm.statistics_mut().take_interrupt());
+        assert_eq!(vm.statistics().cost_limit(), u64::MAX);
+
+        // A handle outliving the VM does nothing.
+        let handle = vm.statistics().interrupt_handle();
+        drop(vm);
+        handle.interrupt();
+    }
+}
@@ -0,0 +1,204 @@
+use super::wasmedge;
+use crate::{
+    function::Function, global::Global, import_obj::ImportObject, memory::Memory,
+    string::StringRef, table::Table,
+};
+
+/// A store holds the instances of the instantiated (anonymous) module and of
+/// every module registered by name.
+#[derive(Debug)]
+pub struct Store {
+    pub(crate) ctx: *mut wasmedge::WasmEdge_StoreContext,
+    // The store context returned by `WasmEdge_VMGetStoreContext` is owned by the
+    // VM context and must not be deleted here.
+    pub(crate) owned: bool,
+    // The import objects registered by an `Executor`, whose instances are
+    // referred to by the store.
+    pub(crate) import_objs: Vec<ImportObject>,
+}
+
+impl Drop for Store {
+    fn drop(&mut self) {
+        if self.owned {
+            unsafe { wasmedge::WasmEdge_StoreDelete(self.ctx) };
+        }
+    }
+}
+
+// SAFETY: the instances of a store are only reachable through it, and the host
+// functions among them are `Send`. Executing a function mutates the store, so
+// it is not `Sync`.
+unsafe impl Send for Store {}
+
+impl Store {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn from_vm(ctx: *mut wasmedge::WasmEdge_StoreContext) -> Self {
+        assert!(!ctx.is_null(), "WasmEdge VM has no store context");
+        Self {
+            ctx,
+            owned: false,
+            import_objs: Vec::new(),
+        }
+    }
+
+    /// Returns the names of all the modules registered in the store.
+    pub fn module_names(&self) -> Vec<String> {
+        let len = unsafe { wasmedge::WasmEdge_StoreListModuleLength(self.ctx) };
+        let mut names = vec![wasmedge::WasmEdge_String::default(); len as usize];
+        unsafe { wasmedge::WasmEdge_StoreListModule(self.ctx, names.as_mut_ptr(), len) };
+        into_owned_names(names)
+    }
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        let ctx = unsafe { wasmedge::WasmEdge_StoreCreate() };
+        assert!(!ctx.is_null(), "failed to create WasmEdge store");
+        Self {
+            ctx,
+            owned: true,
+            import_objs: Vec::new(),
+        }
+    }
+}
+
+fn into_owned_names(names: Vec<wasmedge::WasmEdge_String>) -> Vec<String> {
+    names
+        .into_iter()
+        .map(|name| StringRef::from_raw(name).into())
+        .collect()
+}
+
+/// Code Generate for the `WasmEdge_StoreFind*` and `WasmEdge_StoreList*` functions :
+///
+/// ```ignore
+/// impl Store {
+///     pub fn find_function(&mut self, name: impl AsRef<str>) -> Option<Function<'_>> {
+///         let raw_name: wasmedge::WasmEdge_String = StringRef::from(name.as_ref()).into();
+///         Function::from_raw(unsafe { wasmedge::WasmEdge_StoreFindFunction(self.ctx, raw_name) })
+///     }
+///     pub fn find_function_registered(
+///         &mut self,
+///         mod_name: impl AsRef<str>,
+///         name: impl AsRef<str>,
+///     ) -> Option<Function<'_>> { /* ... */ }
+///     pub fn function_names(&self) -> Vec<String> { /* ... */ }
+///     pub fn function_names_registered(&self, mod_name: impl AsRef<str>) -> Vec<String> { /* ... */ }
+///     // ...
+/// }
+/// ```
+macro_rules! impl_store_instances {
+    ($( $instance:ident ),+ $(,)?) => {
+        impl Store {
+            paste::paste! {
+                $(
+                    #[doc = "Returns the exported " $instance:lower " `name` of the anonymous module."]
+                    pub fn [<find_ $instance:lower>](
+                        &mut self,
+                        name: impl AsRef<str>,
+                    ) -> Option<$instance<'_>> {
+                        let raw_name: wasmedge::WasmEdge_String = StringRef::from(name.as_ref()).into();
+                        $instance::from_raw(unsafe {
+                            wasmedge::[<WasmEdge_StoreFind $instance>](self.ctx, raw_name)
+                        })
+                    }
+
+                    #[doc = "Returns the exported " $instance:lower " `name` of the registered module `mod_name`."]
+                    pub fn [<find_ $instance:lower _registered>](
+                        &mut self,
+                        mod_name: impl AsRef<str>,
+                        name: impl AsRef<str>,
+                    ) -> Option<$instance<'_>> {
+                        let raw_mod_name: wasmedge::WasmEdge_String =
+                            StringRef::from(mod_name.as_ref()).into();
+                        let raw_name: wasmedge::WasmEdge_String = StringRef::from(name.as_ref()).into();
+                        $instance::from_raw(unsafe {
+                            wasmedge::[<WasmEdge_StoreFind $instance Registered>](
+                                self.ctx,
+                                raw_mod_name,
+                                raw_name,
+                            )
+                        })
+                    }
+
+                    #[doc = "Returns the names of the exported " $instance:lower "s of the anonymous module."]
+                    pub fn [<$instance:lower _names>](&self) -> Vec<String> {
+                        let len = unsafe { wasmedge::[<WasmEdge_StoreList $instance Length>](self.ctx) };
+                        let mut names = vec![wasmedge::WasmEdge_String::default(); len as usize];
+                        unsafe {
+                            wasmedge::[<WasmEdge_StoreList $instance>](self.ctx, names.as_mut_ptr(), len)
+                        };
+                        into_owned_names(names)
+                    }
+
+                    #[doc = "Returns the names of the exported " $instance:lower "s of the registered module `mod_name`."]
+                    pub fn [<$instance:lower _names_registered>](
+                        &self,
+                        mod_name: impl AsRef<str>,
+                    ) -> Vec<String> {
+                        let raw_mod_name: wasmedge::WasmEdge_String =
+                            StringRef::from(mod_name.as_ref()).into();
+                        let len = unsafe {
+                            wasmedge::[<WasmEdge_StoreList $instance RegisteredLength>](
+                                self.ctx,
+                                raw_mod_name,
+                            )
+                        };
+                        let mut names = vec![wasmedge::WasmEdge_String::default(); len as usize];
+                        unsafe {
+                            wasmedge::[<WasmEdge_StoreList $instance Registered>](
+                                self.ctx,
+                                raw_mod_name,
+                                names.as_mut_ptr(),
+                                len,
+                            )
+                        };
+                        into_owned_names(names)
+                    }
+                )+
+            }
+        }
+    }
+}
+
+impl_store_instances! {
+    Function,
+    Table,
+    Memory,
+    Global,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, Module, Vm};
+    use std::ffi::CString;
+
+    #[test]
+    fn lists_instances_of_the_active_module() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Config::default();
+        let module =
+            Module::load_from_file(&config, CString::new(path.to_str().unwrap()).unwrap()).unwrap();
+
+        let mut vm = Vm::create(&config, Some(Store::new()))
+            .load_wasm_from_ast_module(&module)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .instantiate()
+            .unwrap();
+
+        let store = vm.store_mut();
+        assert_eq!(store.function_names(), vec!["fib".to_string()]);
+        assert!(store.memory_names().is_empty());
+        assert!(store.module_names().is_empty());
+        assert!(store.find_function("fib").is_some());
+        assert!(store.find_function("fac").is_none());
+        assert!(store.find_function_registered("extern", "fib").is_none());
+    }
+}
@@ -93,6 +93,15 @@ pub struct StringRef<'a> {
 }
 
 impl StringRef<'_> {
+    /// Wraps a `WasmEdge_String` owned by a WasmEdge context, such as the names
+    /// returned by the `List` functions of a store.
+    pub(crate) fn from_raw(inner: wasmedge::WasmEdge_String) -> Self {
+        Self {
+            inner,
+            lifetime: PhantomData,
+        }
+    }
+
     pub fn to_owned(self) -> StringBuf {
         StringBuf {
             inner: unsafe {
@@ -118,3 +127,15 @@ impl From<StringRef<'_>> for wasmedge::WasmEdge_String {
         s.inner
     }
 }
+
+impl From<StringRef<'_>> for String {
+    fn from(s: StringRef<'_>) -> Self {
+        if s.inner.Buf.is_null() || s.inner.Length == 0 {
+            return String::new();
+        }
+        let bytes = unsafe {
+            std::slice::from_raw_parts(s.inner.Buf as *const u8, s.inner.Length as usize)
+        };
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
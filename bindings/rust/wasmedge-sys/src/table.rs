@@ -0,0 +1,181 @@
+use super::wasmedge;
+use crate::{
+    raw_result::{decode_result, ErrReport},
+    store::Store,
+    types::TableType,
+    value::Value,
+};
+use std::marker::PhantomData;
+
+/// A handle to a table instance living in a [`Store`], or a host table waiting
+/// to be added to an [`ImportObject`](crate::ImportObject).
+///
+/// The elements are references: [`Value::FuncRef`] or [`Value::ExternRef`],
+/// depending on the [type](Table::ty) of the table.
+#[derive(Debug)]
+pub struct Table<'store> {
+    pub(crate) ctx: *mut wasmedge::WasmEdge_TableInstanceContext,
+    // Only set for host tables, which own their context until they are moved
+    // into an import object.
+    pub(crate) owned: bool,
+    pub(crate) store: PhantomData<&'store mut Store>,
+}
+
+impl Drop for Table<'_> {
+    fn drop(&mut self) {
+        if self.owned {
+            unsafe { wasmedge::WasmEdge_TableInstanceDelete(self.ctx) };
+        }
+    }
+}
+
+impl Table<'static> {
+    /// Creates a host table of type `ty`, whose elements are null references.
+    pub fn create(ty: TableType) -> Self {
+        let ty_ctx = ty.to_raw();
+        let ctx = unsafe { wasmedge::WasmEdge_TableInstanceCreate(ty_ctx) };
+        unsafe { wasmedge::WasmEdge_TableTypeDelete(ty_ctx) };
+        assert!(!ctx.is_null(), "failed to create WasmEdge table");
+
+        Self {
+            ctx,
+            owned: true,
+            store: PhantomData,
+        }
+    }
+}
+
+impl Table<'_> {
+    pub(crate) fn from_raw(ctx: *mut wasmedge::WasmEdge_TableInstanceContext) -> Option<Self> {
+        if ctx.is_null() {
+            None
+        } else {
+            Some(Self {
+                ctx,
+                owned: false,
+                store: PhantomData,
+            })
+        }
+    }
+
+    /// Returns the type of the table, whose limits are the ones it was created
+    /// with.
+    pub fn ty(&self) -> TableType {
+        unsafe { TableType::from_raw(wasmedge::WasmEdge_TableInstanceGetTableType(self.ctx)) }
+    }
+
+    /// Returns the number of elements in the table.
+    pub fn size(&self) -> u32 {
+        unsafe { wasmedge::WasmEdge_TableInstanceGetSize(self.ctx) }
+    }
+
+    /// Grows the table by `size` null elements.
+    pub fn grow(&mut self, size: u32) -> Result<(), ErrReport> {
+        unsafe { decode_result(wasmedge::WasmEdge_TableInstanceGrow(self.ctx, size)) }
+    }
+
+    /// Returns the element at `idx`.
+    pub fn get(&self, idx: u32) -> Result<Value, ErrReport> {
+        let mut raw = wasmedge::WasmEdge_Value::from(Value::I32(0));
+        unsafe {
+            decode_result(wasmedge::WasmEdge_TableInstanceGetData(
+                self.ctx, &mut raw, idx,
+            ))?
+        };
+        Ok(Value::from_raw(self.ty().elem_ty.into(), raw))
+    }
+
+    /// Sets the element at `idx` to `value`, which must be a reference of the
+    /// element type of the table.
+    pub fn set(&mut self, idx: u32, value: Value) -> Result<(), ErrReport> {
+        unsafe {
+            decode_result(wasmedge::WasmEdge_TableInstanceSetData(
+                self.ctx,
+                value.into(),
+                idx,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::{Limit, RefType},
+        Config, ExternRef, Vm,
+    };
+    use std::ptr::NonNull;
+
+    #[test]
+    fn accesses_exported_table() {
+        // (module
+        //   (type (func (result i32)))
+        //   (table (export "table") 2 4 funcref)
+        //   (elem (i32.const 0) 0)
+        //   (func (result i32) (i32.const 42))
+        //   (func (export "call") (param i32) (result i32)
+        //     (call_indirect (type 0) (local.get 0))))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x0a, 0x02, 0x60, 0x00, 0x01,
+            0x7f, 0x60, 0x01, 0x7f, 0x01, 0x7f, 0x03, 0x03, 0x02, 0x00, 0x01, 0x04, 0x05, 0x01,
+            0x70, 0x01, 0x02, 0x04, 0x07, 0x10, 0x02, 0x05, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x01,
+            0x00, 0x04, 0x63, 0x61, 0x6c, 0x6c, 0x00, 0x01, 0x09, 0x07, 0x01, 0x00, 0x41, 0x00,
+            0x0b, 0x01, 0x00, 0x0a, 0x0e, 0x02, 0x04, 0x00, 0x41, 0x2a, 0x0b, 0x07, 0x00, 0x20,
+            0x00, 0x11, 0x00, 0x00, 0x0b,
+        ];
+
+        let config = Config::default();
+        let vm = Vm::create(&config, None);
+        let mut vm = vm
+            .load_wasm_from_bytes(wasm)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .instantiate()
+            .unwrap();
+        assert!(vm.run("call", &[Value::I32(1)]).is_err());
+
+        {
+            let mut table = vm.store_mut().find_table("table").unwrap();
+            assert_eq!(
+                table.ty(),
+                TableType::new(RefType::FuncRef, Limit::new(2, Some(4)))
+            );
+            assert_eq!(table.size(), 2);
+
+            let func_ref = table.get(0).unwrap();
+            assert!(matches!(func_ref, Value::FuncRef(Some(_))));
+            assert_eq!(table.get(1).unwrap(), Value::FuncRef(None));
+            assert!(table.get(2).is_err());
+
+            table.set(1, func_ref).unwrap();
+            assert!(table.set(0, Value::ExternRef(None)).is_err());
+            assert!(table.set(2, func_ref).is_err());
+
+            table.grow(2).unwrap();
+            assert_eq!(table.size(), 4);
+            assert_eq!(table.get(3).unwrap(), Value::FuncRef(None));
+            assert!(table.grow(1).is_err());
+        }
+
+        assert_eq!(
+            vm.run("call", &[Value::I32(1)]).unwrap(),
+            vec![Value::I32(42)]
+        );
+    }
+
+    #[test]
+    fn creates_host_table() {
+        let mut data = 42u64;
+        let ext_ref = ExternRef::new(NonNull::from(&mut data));
+
+        let mut table = Table::create(TableType::new(RefType::ExternRef, Limit::new(1, None)));
+        assert_eq!(table.ty().limit, Limit::new(1, None));
+        assert_eq!(table.get(0).unwrap(), Value::ExternRef(None));
+
+        table.set(0, Value::ExternRef(Some(ext_ref))).unwrap();
+        assert_eq!(table.get(0).unwrap(), Value::ExternRef(Some(ext_ref)));
+        assert!(table.set(0, Value::FuncRef(None)).is_err());
+    }
+}
@@ -0,0 +1,434 @@
+use super::wasmedge;
+use crate::value::UnknownValType;
+use std::{convert::TryFrom, fmt};
+
+/// The type of a Wasm value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum ValType {
+    I32 = wasmedge::WasmEdge_ValType_I32,
+    I64 = wasmedge::WasmEdge_ValType_I64,
+    F32 = wasmedge::WasmEdge_ValType_F32,
+    F64 = wasmedge::WasmEdge_ValType_F64,
+    V128 = wasmedge::WasmEdge_ValType_V128,
+    FuncRef = wasmedge::WasmEdge_ValType_FuncRef,
+    ExternRef = wasmedge::WasmEdge_ValType_ExternRef,
+}
+
+impl ValType {
+    /// Converts a type returned by a WasmEdge context, which is always valid.
+    pub fn from_raw(raw: wasmedge::WasmEdge_ValType) -> Self {
+        Self::try_from(raw).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl TryFrom<wasmedge::WasmEdge_ValType> for ValType {
+    type Error = UnknownValType;
+
+    fn try_from(raw: wasmedge::WasmEdge_ValType) -> Result<Self, Self::Error> {
+        match raw {
+            wasmedge::WasmEdge_ValType_I32 => Ok(Self::I32),
+            wasmedge::WasmEdge_ValType_I64 => Ok(Self::I64),
+            wasmedge::WasmEdge_ValType_F32 => Ok(Self::F32),
+            wasmedge::WasmEdge_ValType_F64 => Ok(Self::F64),
+            wasmedge::WasmEdge_ValType_V128 => Ok(Self::V128),
+            wasmedge::WasmEdge_ValType_FuncRef => Ok(Self::FuncRef),
+            wasmedge::WasmEdge_ValType_ExternRef => Ok(Self::ExternRef),
+            _ => Err(UnknownValType(raw)),
+        }
+    }
+}
+
+impl fmt::Display for ValType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+            Self::V128 => "v128",
+            Self::FuncRef => "funcref",
+            Self::ExternRef => "externref",
+        })
+    }
+}
+
+/// The signature of a function.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FuncType {
+    pub params: Vec<ValType>,
+    pub results: Vec<ValType>,
+}
+
+impl FuncType {
+    pub fn new(params: impl Into<Vec<ValType>>, results: impl Into<Vec<ValType>>) -> Self {
+        Self {
+            params: params.into(),
+            results: results.into(),
+        }
+    }
+
+    /// Creates a `WasmEdge_FunctionTypeContext`, which the caller must delete.
+    pub fn to_raw(&self) -> *mut wasmedge::WasmEdge_FunctionTypeContext {
+        let params: Vec<_> = self.params.iter().map(|ty| *ty as u32).collect();
+        let results: Vec<_> = self.results.iter().map(|ty| *ty as u32).collect();
+        let ctx = unsafe {
+            wasmedge::WasmEdge_FunctionTypeCreate(
+                params.as_ptr(),
+                params.len() as u32,
+                results.as_ptr(),
+                results.len() as u32,
+            )
+        };
+        assert!(!ctx.is_null(), "failed to create WasmEdge function type");
+        ctx
+    }
+
+    /// Reads a `WasmEdge_FunctionTypeContext`.
+    ///
+    /// # Safety
+    ///
+    /// `ctx` must point to a live function type context.
+    pub unsafe fn from_raw(ctx: *const wasmedge::WasmEdge_FunctionTypeContext) -> Self {
+        let params_len = unsafe { wasmedge::WasmEdge_FunctionTypeGetParametersLength(ctx) };
+        let mut params = vec![0; params_len as usize];
+        unsafe {
+            wasmedge::WasmEdge_FunctionTypeGetParameters(ctx, params.as_mut_ptr(), params_len)
+        };
+
+        let results_len = unsafe { wasmedge::WasmEdge_FunctionTypeGetReturnsLength(ctx) };
+        let mut results = vec![0; results_len as usize];
+        unsafe {
+            wasmedge::WasmEdge_FunctionTypeGetReturns(ctx, results.as_mut_ptr(), results_len)
+        };
+
+        Self {
+            params: params.into_iter().map(ValType::from_raw).collect(),
+            results: results.into_iter().map(ValType::from_raw).collect(),
+        }
+    }
+}
+
+/// Formats the signature as in the text format, e.g. `(func (param i32) (result i32))`.
+impl fmt::Display for FuncType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(func")?;
+        for (keyword, types) in [("param", &self.params), ("result", &self.results)] {
+            if !types.is_empty() {
+                write!(f, " ({}", keyword)?;
+                for ty in types {
+                    write!(f, " {}", ty)?;
+                }
+                f.write_str(")")?;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+/// The size range of a memory or a table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Limit {
+    pub min: u32,
+    pub max: Option<u32>,
+}
+
+impl Limit {
+    pub fn new(min: u32, max: Option<u32>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl From<wasmedge::WasmEdge_Limit> for Limit {
+    fn from(limit: wasmedge::WasmEdge_Limit) -> Self {
+        Self {
+            min: limit.Min,
+            max: if limit.HasMax { Some(limit.Max) } else { None },
+        }
+    }
+}
+
+impl From<Limit> for wasmedge::WasmEdge_Limit {
+    fn from(limit: Limit) -> Self {
+        Self {
+            HasMax: limit.max.is_some(),
+            Min: limit.min,
+            Max: limit.max.unwrap_or(0),
+        }
+    }
+}
+
+/// Formats the limits as in the text format, e.g. `1 2`, or `1` without maximum.
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max {
+            Some(max) => write!(f, "{} {}", self.min, max),
+            None => write!(f, "{}", self.min),
+        }
+    }
+}
+
+/// The type of a linear memory, with limits in pages of 64 KiB.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MemoryType {
+    pub limit: Limit,
+}
+
+impl MemoryType {
+    pub fn new(limit: Limit) -> Self {
+        Self { limit }
+    }
+
+    /// Creates a `WasmEdge_MemoryTypeContext`, which the caller must delete.
+    pub fn to_raw(self) -> *mut wasmedge::WasmEdge_MemoryTypeContext {
+        let ctx = unsafe { wasmedge::WasmEdge_MemoryTypeCreate(self.limit.into()) };
+        assert!(!ctx.is_null(), "failed to create WasmEdge memory type");
+        ctx
+    }
+
+    /// Reads a `WasmEdge_MemoryTypeContext`.
+    ///
+    /// # Safety
+    ///
+    /// `ctx` must point to a live memory type context.
+    pub unsafe fn from_raw(ctx: *const wasmedge::WasmEdge_MemoryTypeContext) -> Self {
+        Self {
+            limit: unsafe { wasmedge::WasmEdge_MemoryTypeGetLimit(ctx) }.into(),
+        }
+    }
+}
+
+impl fmt::Display for MemoryType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(memory {})", self.limit)
+    }
+}
+
+/// The type of a reference, i.e. of the elements of a table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum RefType {
+    FuncRef = wasmedge::WasmEdge_RefType_FuncRef,
+    ExternRef = wasmedge::WasmEdge_RefType_ExternRef,
+}
+
+impl RefType {
+    /// Converts a type returned by a WasmEdge context, which is always valid.
+    pub fn from_raw(raw: wasmedge::WasmEdge_RefType) -> Self {
+        match raw {
+            wasmedge::WasmEdge_RefType_FuncRef => Self::FuncRef,
+            wasmedge::WasmEdge_RefType_ExternRef => Self::ExternRef,
+            _ => panic!("unknown WasmEdge reference type {}", raw),
+        }
+    }
+}
+
+impl From<RefType> for ValType {
+    fn from(ty: RefType) -> Self {
+        match ty {
+            RefType::FuncRef => Self::FuncRef,
+            RefType::ExternRef => Self::ExternRef,
+        }
+    }
+}
+
+impl fmt::Display for RefType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ValType::from(*self).fmt(f)
+    }
+}
+
+/// The type of a table, with limits in number of elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TableType {
+    pub elem_ty: RefType,
+    pub limit: Limit,
+}
+
+impl TableType {
+    pub fn new(elem_ty: RefType, limit: Limit) -> Self {
+        Self { elem_ty, limit }
+    }
+
+    /// Creates a `WasmEdge_TableTypeContext`, which the caller must delete.
+    pub fn to_raw(self) -> *mut wasmedge::WasmEdge_TableTypeContext {
+        let ctx =
+            unsafe { wasmedge::WasmEdge_TableTypeCreate(self.elem_ty as u32, self.limit.into()) };
+        assert!(!ctx.is_null(), "failed to create WasmEdge table type");
+        ctx
+    }
+
+    /// Reads a `WasmEdge_TableTypeContext`.
+    ///
+    /// # Safety
+    ///
+    /// `ctx` must point to a live table type context.
+    pub unsafe fn from_raw(ctx: *const wasmedge::WasmEdge_TableTypeContext) -> Self {
+        Self {
+            elem_ty: RefType::from_raw(unsafe { wasmedge::WasmEdge_TableTypeGetRefType(ctx) }),
+            limit: unsafe { wasmedge::WasmEdge_TableTypeGetLimit(ctx) }.into(),
+        }
+    }
+}
+
+impl fmt::Display for TableType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(table {} {})", self.limit, self.elem_ty)
+    }
+}
+
+/// Whether the value of a global can be changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum Mutability {
+    Const = wasmedge::WasmEdge_Mutability_Const,
+    Var = wasmedge::WasmEdge_Mutability_Var,
+}
+
+impl Mutability {
+    /// Converts a mutability returned by a WasmEdge context, which is always
+    /// valid.
+    pub fn from_raw(raw: wasmedge::WasmEdge_Mutability) -> Self {
+        match raw {
+            wasmedge::WasmEdge_Mutability_Const => Self::Const,
+            wasmedge::WasmEdge_Mutability_Var => Self::Var,
+            _ => panic!("unknown WasmEdge mutability {}", raw),
+        }
+    }
+}
+
+/// The type of a global.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GlobalType {
+    pub ty: ValType,
+    pub mutability: Mutability,
+}
+
+impl GlobalType {
+    pub fn new(ty: ValType, mutability: Mutability) -> Self {
+        Self { ty, mutability }
+    }
+
+    /// Creates a `WasmEdge_GlobalTypeContext`, which the caller must delete.
+    pub fn to_raw(self) -> *mut wasmedge::WasmEdge_GlobalTypeContext {
+        let ctx =
+            unsafe { wasmedge::WasmEdge_GlobalTypeCreate(self.ty as u32, self.mutability as u32) };
+        assert!(!ctx.is_null(), "failed to create WasmEdge global type");
+        ctx
+    }
+
+    /// Reads a `WasmEdge_GlobalTypeContext`.
+    ///
+    /// # Safety
+    ///
+    /// `ctx` must point to a live global type context.
+    pub unsafe fn from_raw(ctx: *const wasmedge::WasmEdge_GlobalTypeContext) -> Self {
+        Self {
+            ty: ValType::from_raw(unsafe { wasmedge::WasmEdge_GlobalTypeGetValType(ctx) }),
+            mutability: Mutability::from_raw(unsafe {
+                wasmedge::WasmEdge_GlobalTypeGetMutability(ctx)
+            }),
+        }
+    }
+}
+
+impl fmt::Display for GlobalType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mutability {
+            Mutability::Const => write!(f, "(global {})", self.ty),
+            Mutability::Var => write!(f, "(global (mut {}))", self.ty),
+        }
+    }
+}
+
+/// The type of an imported or exported instance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExternType {
+    Func(FuncType),
+    Table(TableType),
+    Memory(MemoryType),
+    Global(GlobalType),
+}
+
+impl fmt::Display for ExternType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Func(ty) => ty.fmt(f),
+            Self::Table(ty) => ty.fmt(f),
+            Self::Memory(ty) => ty.fmt(f),
+            Self::Global(ty) => ty.fmt(f),
+        }
+    }
+}
+
+/// An import of a module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportType {
+    pub module: String,
+    pub name: String,
+    pub ty: ExternType,
+}
+
+/// An export of a module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportType {
+    pub name: String,
+    pub ty: ExternType,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_contexts() {
+        let func_ty = FuncType::new([ValType::I32, ValType::ExternRef], [ValType::V128]);
+        let ctx = func_ty.to_raw();
+        assert_eq!(unsafe { FuncType::from_raw(ctx) }, func_ty);
+        unsafe { wasmedge::WasmEdge_FunctionTypeDelete(ctx) };
+
+        let mem_ty = MemoryType::new(Limit::new(1, None));
+        let ctx = mem_ty.to_raw();
+        assert_eq!(unsafe { MemoryType::from_raw(ctx) }, mem_ty);
+        unsafe { wasmedge::WasmEdge_MemoryTypeDelete(ctx) };
+
+        let table_ty = TableType::new(RefType::ExternRef, Limit::new(0, Some(8)));
+        let ctx = table_ty.to_raw();
+        assert_eq!(unsafe { TableType::from_raw(ctx) }, table_ty);
+        unsafe { wasmedge::WasmEdge_TableTypeDelete(ctx) };
+
+        let global_ty = GlobalType::new(ValType::F64, Mutability::Var);
+        let ctx = global_ty.to_raw();
+        assert_eq!(unsafe { GlobalType::from_raw(ctx) }, global_ty);
+        unsafe { wasmedge::WasmEdge_GlobalTypeDelete(ctx) };
+    }
+
+    #[test]
+    fn displays_as_text_format() {
+        assert_eq!(FuncType::default().to_string(), "(func)");
+        assert_eq!(
+            FuncType::new([ValType::I32, ValType::F64], [ValType::FuncRef]).to_string(),
+            "(func (param i32 f64) (result funcref))"
+        );
+        assert_eq!(
+            FuncType::new([], [ValType::V128]).to_string(),
+            "(func (result v128))"
+        );
+        assert_eq!(
+            MemoryType::new(Limit::new(1, Some(2))).to_string(),
+            "(memory 1 2)"
+        );
+        assert_eq!(
+            TableType::new(RefType::ExternRef, Limit::new(4, None)).to_string(),
+            "(table 4 externref)"
+        );
+        assert_eq!(
+            GlobalType::new(ValType::I64, Mutability::Const).to_string(),
+            "(global i64)"
+        );
+        assert_eq!(
+            ExternType::Global(GlobalType::new(ValType::I32, Mutability::Var)).to_string(),
+            "(global (mut i32))"
+        );
+    }
+}
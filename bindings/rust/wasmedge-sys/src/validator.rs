@@ -0,0 +1,35 @@
+use super::wasmedge;
+use crate::{
+    config::Config,
+    module::Module,
+    raw_result::{decode_result, ErrReport},
+};
+
+/// Checks that AST modules are valid, which is required before they are
+/// instantiated by an [`Executor`](crate::Executor).
+#[derive(Debug)]
+pub struct Validator {
+    pub(crate) ctx: *mut wasmedge::WasmEdge_ValidatorContext,
+}
+
+impl Drop for Validator {
+    fn drop(&mut self) {
+        unsafe { wasmedge::WasmEdge_ValidatorDelete(self.ctx) };
+    }
+}
+
+// SAFETY: a validator has no affinity to the thread that created it, but keeps
+// state while validating, so it is not `Sync`.
+unsafe impl Send for Validator {}
+
+impl Validator {
+    pub fn create(config: &Config) -> Self {
+        let ctx = unsafe { wasmedge::WasmEdge_ValidatorCreate(config.ctx) };
+        assert!(!ctx.is_null(), "failed to create WasmEdge validator");
+        Self { ctx }
+    }
+
+    pub fn validate(&self, module: &Module) -> Result<(), ErrReport> {
+        decode_result(unsafe { wasmedge::WasmEdge_ValidatorValidate(self.ctx, module.ctx) })
+    }
+}
@@ -1,13 +1,104 @@
 use super::wasmedge;
+use crate::types::ValType;
+use std::{convert::TryFrom, fmt, os::raw::c_void, ptr::NonNull};
 
-/// A polymorphic Wasm primitive type.
-/// # TODO : v128 / Reference types
+/// A polymorphic Wasm value.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Value {
     I32(i32),
     I64(i64),
     F32(f32),
     F64(f64),
+    V128(u128),
+    /// A reference to the function at this index in the store, or a null reference.
+    FuncRef(Option<u32>),
+    /// An opaque host reference, or a null reference.
+    ExternRef(Option<ExternRef>),
+}
+
+/// An opaque pointer to host data, passed to guests as an `externref`.
+///
+/// WasmEdge never dereferences the pointer: keeping the data alive while the
+/// guest may hold the reference is up to the host.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExternRef(NonNull<c_void>);
+
+// SAFETY: the pointer is opaque to WasmEdge and to this crate, which never
+// dereference it, so sharing it across threads is up to the host like keeping
+// the data alive.
+unsafe impl Send for ExternRef {}
+unsafe impl Sync for ExternRef {}
+
+// Values cross threads, e.g. as the results of a call.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Value>();
+};
+
+impl ExternRef {
+    pub fn new<T>(ptr: NonNull<T>) -> Self {
+        Self(ptr.cast())
+    }
+
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.0.as_ptr()
+    }
+}
+
+/// The error returned when converting a `WasmEdge_Value` of unknown type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownValType(pub wasmedge::WasmEdge_ValType);
+
+impl fmt::Display for UnknownValType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown WasmEdge_ValType `{}`", self.0)
+    }
+}
+
+impl std::error::Error for UnknownValType {}
+
+impl Value {
+    /// Returns the type of the value.
+    pub fn ty(&self) -> ValType {
+        match self {
+            Self::I32(_) => ValType::I32,
+            Self::I64(_) => ValType::I64,
+            Self::F32(_) => ValType::F32,
+            Self::F64(_) => ValType::F64,
+            Self::V128(_) => ValType::V128,
+            Self::FuncRef(_) => ValType::FuncRef,
+            Self::ExternRef(_) => ValType::ExternRef,
+        }
+    }
+
+    /// Decodes `value` as a value of type `ty`, ignoring `value.Type`.
+    ///
+    /// WasmEdge does not set the type of the values returned by a function, so
+    /// they have to be decoded with the types of its signature.
+    pub fn from_raw(ty: ValType, value: wasmedge::WasmEdge_Value) -> Self {
+        match ty {
+            ValType::I32 => Self::I32(value.Value as i32),
+            ValType::I64 => Self::I64(value.Value as i64),
+            ValType::F32 => Self::F32(f32::from_bits(value.Value as u32)),
+            ValType::F64 => Self::F64(f64::from_bits(value.Value as u64)),
+            ValType::V128 => Self::V128(value.Value),
+            ValType::FuncRef => {
+                Self::FuncRef(if unsafe { wasmedge::WasmEdge_ValueIsNullRef(value) } {
+                    None
+                } else {
+                    Some(unsafe { wasmedge::WasmEdge_ValueGetFuncIdx(value) })
+                })
+            }
+            ValType::ExternRef => {
+                Self::ExternRef(if unsafe { wasmedge::WasmEdge_ValueIsNullRef(value) } {
+                    None
+                } else {
+                    NonNull::new(unsafe { wasmedge::WasmEdge_ValueGetExternRef(value) })
+                        .map(ExternRef)
+                })
+            }
+        }
+    }
 }
 
 impl From<Value> for wasmedge::WasmEdge_Value {
@@ -29,19 +120,30 @@ impl From<Value> for wasmedge::WasmEdge_Value {
                 Value: v.to_bits() as u128,
                 Type: wasmedge::WasmEdge_ValType_F64,
             },
+            Value::V128(v) => Self {
+                Value: v,
+                Type: wasmedge::WasmEdge_ValType_V128,
+            },
+            Value::FuncRef(Some(idx)) => unsafe { wasmedge::WasmEdge_ValueGenFuncRef(idx) },
+            Value::FuncRef(None) => unsafe {
+                wasmedge::WasmEdge_ValueGenNullRef(wasmedge::WasmEdge_RefType_FuncRef)
+            },
+            Value::ExternRef(Some(ext_ref)) => unsafe {
+                wasmedge::WasmEdge_ValueGenExternRef(ext_ref.as_ptr())
+            },
+            Value::ExternRef(None) => unsafe {
+                wasmedge::WasmEdge_ValueGenNullRef(wasmedge::WasmEdge_RefType_ExternRef)
+            },
         }
     }
 }
 
-impl From<wasmedge::WasmEdge_Value> for Value {
-    fn from(value: wasmedge::WasmEdge_Value) -> Self {
-        match value.Type {
-            wasmedge::WasmEdge_ValType_I32 => Self::I32(value.Value as i32),
-            wasmedge::WasmEdge_ValType_I64 => Self::I64(value.Value as i64),
-            wasmedge::WasmEdge_ValType_F32 => Self::F32(f32::from_bits(value.Value as u32)),
-            wasmedge::WasmEdge_ValType_F64 => Self::F64(f64::from_bits(value.Value as u64)),
-            _ => panic!("unknown WasmEdge_ValType `{}`", value.Type),
-        }
+impl TryFrom<wasmedge::WasmEdge_Value> for Value {
+    type Error = UnknownValType;
+
+    fn try_from(value: wasmedge::WasmEdge_Value) -> Result<Self, Self::Error> {
+        let ty = ValType::try_from(value.Type)?;
+        Ok(Self::from_raw(ty, value))
     }
 }
 
@@ -64,6 +166,7 @@ impl_from_prim_conversions! {
     [u32, i64] => I64,
     [f32] => F32,
     [f64] => F64,
+    [u128] => V128,
 }
 
 macro_rules! impl_to_prim_conversions {
@@ -93,4 +196,40 @@ impl_to_prim_conversions! {
     [I32, I64, F32, F64] => i64,
     [F32] => f32,
     [F32, F64] => f64,
+    [V128] => u128,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_wasmedge_value() {
+        let mut data = 42u64;
+        let values = [
+            Value::I32(-1),
+            Value::I64(i64::MIN),
+            Value::F32(1.5),
+            Value::F64(-0.25),
+            Value::V128(u128::MAX - 1),
+            Value::FuncRef(Some(3)),
+            Value::FuncRef(None),
+            Value::ExternRef(Some(ExternRef::new(NonNull::from(&mut data)))),
+            Value::ExternRef(None),
+        ];
+        for value in values.iter().copied() {
+            let raw = wasmedge::WasmEdge_Value::from(value);
+            assert_eq!(Value::try_from(raw), Ok(value));
+            assert_eq!(Value::from_raw(value.ty(), raw), value);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_type() {
+        let raw = wasmedge::WasmEdge_Value {
+            Value: 0,
+            Type: 0x40,
+        };
+        assert_eq!(Value::try_from(raw), Err(UnknownValType(0x40)));
+    }
 }
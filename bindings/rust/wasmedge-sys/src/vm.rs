@@ -1,26 +1,151 @@
 use super::wasmedge;
 use crate::{
-    raw_result::{decode_result, ErrReport},
+    import_obj::ImportObject,
+    raw_result::{decode_result, get_code, ErrReport, ErrorKind},
+    statistics::Statistics,
+    store::Store,
     string::StringRef,
+    types::FuncType,
     value::Value,
-    wasi,
 };
-use std::os::raw::c_char;
+use std::{
+    ffi::CString,
+    os::raw::{c_char, c_int},
+};
 
 // If there is a third-party sdk based on wasmedge-sys
 // the private encapsulation  here can force the third-party sdk to use the api
 #[derive(Debug)]
 pub struct Vm {
     pub(crate) ctx: *mut wasmedge::WasmEdge_VMContext,
+    // Dropped after the VM context, which may still refer to it.
+    store: Store,
+    statistics: Statistics,
+    // The registered import objects, whose instances are referred to by the store.
+    import_objs: Vec<ImportObject>,
+    // Whether the last execution stopped early with `proc_exit` or a
+    // terminating host function, which WasmEdge reports as a success.
+    terminated: bool,
 }
 
 impl Vm {
-    pub fn create(config: &crate::config::Config) -> Self {
-        let ctx = unsafe {
-            wasmedge::WasmEdge_VMCreate(config.ctx, std::ptr::null_mut() /* store */)
-        };
+    /// Creates a VM on top of `store`, or on a store owned by the VM if `None`.
+    pub fn create(config: &crate::config::Config, store: Option<Store>) -> Self {
+        let store_ctx = store
+            .as_ref()
+            .map_or(std::ptr::null_mut(), |store| store.ctx);
+        let ctx = unsafe { wasmedge::WasmEdge_VMCreate(config.ctx, store_ctx) };
         assert!(!ctx.is_null(), "WasmEdge VM create failed");
-        Self { ctx }
+
+        let store = match store {
+            Some(store) => store,
+            None => Store::from_vm(unsafe { wasmedge::WasmEdge_VMGetStoreContext(ctx) }),
+        };
+        let statistics =
+            Statistics::from_vm(unsafe { wasmedge::WasmEdge_VMGetStatisticsContext(ctx) });
+        Self {
+            ctx,
+            store,
+            statistics,
+            import_objs: Vec::new(),
+            terminated: false,
+        }
+    }
+
+    /// Returns the store the VM instantiates and registers modules into.
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    pub fn store_mut(&mut self) -> &mut Store {
+        &mut self.store
+    }
+
+    /// Returns the statistics of the executions of the VM.
+    pub fn statistics(&self) -> &Statistics {
+        &self.statistics
+    }
+
+    pub fn statistics_mut(&mut self) -> &mut Statistics {
+        &mut self.statistics
+    }
+
+    /// Registers the host instances of `import_obj` under its module name.
+    ///
+    /// Registering a module resets the instantiated module, so this must be
+    /// called before [`Vm::instantiate`].
+    pub fn register_module_from_import(
+        mut self,
+        import_obj: ImportObject,
+    ) -> Result<Self, ErrReport> {
+        unsafe {
+            decode_result(wasmedge::WasmEdge_VMRegisterModuleFromImport(
+                self.ctx,
+                import_obj.ctx,
+            ))?;
+        }
+        self.import_objs.push(import_obj);
+        Ok(self)
+    }
+
+    /// Registers the exports of the Wasm file `path` under `mod_name`, so that
+    /// the module instantiated afterwards can import them.
+    ///
+    /// Registering a module resets the instantiated module, so this must be
+    /// called before [`Vm::instantiate`].
+    pub fn register_module_from_file(
+        self,
+        mod_name: impl AsRef<str>,
+        path: CString,
+    ) -> Result<Self, ErrReport> {
+        let raw_mod_name: wasmedge::WasmEdge_String = StringRef::from(mod_name.as_ref()).into();
+        unsafe {
+            decode_result(wasmedge::WasmEdge_VMRegisterModuleFromFile(
+                self.ctx,
+                raw_mod_name,
+                path.as_ptr(),
+            ))?;
+        }
+        Ok(self)
+    }
+
+    /// Registers the exports of the Wasm binary `bytes` under `mod_name`.
+    ///
+    /// See [`Vm::register_module_from_file`].
+    pub fn register_module_from_bytes(
+        self,
+        mod_name: impl AsRef<str>,
+        bytes: &[u8],
+    ) -> Result<Self, ErrReport> {
+        let raw_mod_name: wasmedge::WasmEdge_String = StringRef::from(mod_name.as_ref()).into();
+        unsafe {
+            decode_result(wasmedge::WasmEdge_VMRegisterModuleFromBuffer(
+                self.ctx,
+                raw_mod_name,
+                bytes.as_ptr(),
+                bytes.len() as u32,
+            ))?;
+        }
+        Ok(self)
+    }
+
+    /// Registers the exports of `module` under `mod_name`.
+    ///
+    /// See [`Vm::register_module_from_file`].
+    pub fn register_module_from_ast(
+        self,
+        mod_name: impl AsRef<str>,
+        module: &crate::module::Module,
+    ) -> Result<Self, ErrReport> {
+        let raw_mod_name: wasmedge::WasmEdge_String = StringRef::from(mod_name.as_ref()).into();
+        unsafe {
+            decode_result(wasmedge::WasmEdge_VMRegisterModuleFromASTModule(
+                self.ctx,
+                raw_mod_name,
+                module.ctx,
+            ))?;
+        }
+        Ok(self)
     }
 
     pub fn load_wasm_from_ast_module(
@@ -35,6 +160,18 @@ impl Vm {
         Ok(self)
     }
 
+    /// Loads the Wasm binary `bytes` as the module to validate and instantiate.
+    pub fn load_wasm_from_bytes(self, bytes: &[u8]) -> Result<Self, ErrReport> {
+        unsafe {
+            decode_result(wasmedge::WasmEdge_VMLoadWasmFromBuffer(
+                self.ctx,
+                bytes.as_ptr(),
+                bytes.len() as u32,
+            ))?;
+        }
+        Ok(self)
+    }
+
     pub fn validate(self) -> Result<Self, ErrReport> {
         unsafe {
             decode_result(wasmedge::WasmEdge_VMValidate(self.ctx))?;
@@ -49,79 +186,179 @@ impl Vm {
         Ok(self)
     }
 
-    pub fn init_wasi_obj(
-        &self,
-        args: Option<&Vec<&str>>,
-        envs: Option<&Vec<&str>>,
-        dirs: Option<&Vec<&str>>,
-        preopens: Option<&Vec<&str>>,
-    ) {
+    /// Initializes the WASI module registered when the VM was created.
+    ///
+    /// `args` start with the program name, `envs` are `KEY=VALUE` pairs and
+    /// `dirs` are `GUEST_PATH:HOST_PATH` mappings, optionally followed by
+    /// `:readonly` to deny writes.
+    ///
+    /// # Panics
+    ///
+    /// If WASI is not enabled in the config of the VM.
+    pub fn init_wasi(&mut self, args: &[CString], envs: &[CString], dirs: &[CString]) {
+        let import_mod_ctx = self.wasi_import_module();
+        let args: Vec<*const c_char> = args.iter().map(|arg| arg.as_ptr()).collect();
+        let envs: Vec<*const c_char> = envs.iter().map(|env| env.as_ptr()).collect();
+        let dirs: Vec<*const c_char> = dirs.iter().map(|dir| dir.as_ptr()).collect();
+        unsafe {
+            wasmedge::WasmEdge_ImportObjectInitWASI(
+                import_mod_ctx,
+                args.as_ptr(),
+                args.len() as u32,
+                envs.as_ptr(),
+                envs.len() as u32,
+                dirs.as_ptr(),
+                dirs.len() as u32,
+                std::ptr::null(),
+                0,
+            )
+        }
+    }
+
+    /// Redirects the standard input, output and error of the WASI module to
+    /// duplicates of the host file descriptors, or keeps the streams of the
+    /// host process for `None`. This must be called after [`Vm::init_wasi`].
+    ///
+    /// Returns `false` if a descriptor cannot be duplicated, e.g. on Windows
+    /// where this is not supported.
+    ///
+    /// # Panics
+    ///
+    /// If WASI is not enabled in the config of the VM.
+    pub fn set_wasi_stdio(
+        &mut self,
+        stdin: Option<c_int>,
+        stdout: Option<c_int>,
+        stderr: Option<c_int>,
+    ) -> bool {
+        let import_mod_ctx = self.wasi_import_module();
+        unsafe {
+            wasmedge::WasmEdge_ImportObjectWasiSetStdio(
+                import_mod_ctx,
+                stdin.unwrap_or(-1),
+                stdout.unwrap_or(-1),
+                stderr.unwrap_or(-1),
+            )
+        }
+    }
+
+    /// Initializes the `wasmedge_process` module registered when the VM was
+    /// created, allowing guests to run the commands `allowed_cmds`, or any
+    /// command if `allow_all` is set.
+    ///
+    /// # Panics
+    ///
+    /// If `wasmedge_process` is not enabled in the config of the VM.
+    pub fn init_wasmedge_process(&mut self, allowed_cmds: &[CString], allow_all: bool) {
         let import_mod_ctx = unsafe {
             wasmedge::WasmEdge_VMGetImportModuleContext(
                 self.ctx,
-                wasmedge::WasmEdge_HostRegistration_Wasi,
+                wasmedge::WasmEdge_HostRegistration_WasmEdge_Process,
             )
         };
-
-        let mut import_obj = wasi::ImportObjInitParams::new();
-
-        if let Some(args) = args {
-            import_obj.args = args
-                .iter()
-                .map(|arg| {
-                    let raw_arg: wasmedge::WasmEdge_String = StringRef::from(*arg).into();
-                    raw_arg.Buf
-                })
-                .collect::<Vec<*const c_char>>();
-            import_obj.args_len = args.len() as u32;
+        assert!(
+            !import_mod_ctx.is_null(),
+            "wasmedge_process is not enabled in the WasmEdge config"
+        );
+        let allowed_cmds: Vec<*const c_char> =
+            allowed_cmds.iter().map(|cmd| cmd.as_ptr()).collect();
+        unsafe {
+            wasmedge::WasmEdge_ImportObjectInitWasmEdgeProcess(
+                import_mod_ctx,
+                allowed_cmds.as_ptr(),
+                allowed_cmds.len() as u32,
+                allow_all,
+            )
         }
+    }
 
-        if let Some(envs) = envs {
-            import_obj.envs = envs
-                .iter()
-                .map(|env| {
-                    let raw_env: wasmedge::WasmEdge_String = StringRef::from(*env).into();
-                    raw_env.Buf
-                })
-                .collect::<Vec<*const c_char>>();
-            import_obj.envs_len = envs.len() as u32;
+    /// Returns the exit code passed to `proc_exit` if the last execution was
+    /// terminated, or `None` if it returned normally or WASI is not enabled.
+    pub fn wasi_exit_code(&self) -> Option<u32> {
+        if !self.terminated {
+            return None;
+        }
+        let import_mod_ctx = unsafe {
+            wasmedge::WasmEdge_VMGetImportModuleContext(
+                self.ctx,
+                wasmedge::WasmEdge_HostRegistration_Wasi,
+            )
+        };
+        if import_mod_ctx.is_null() {
+            None
+        } else {
+            Some(unsafe { wasmedge::WasmEdge_ImportObjectWasiGetExitCode(import_mod_ctx) })
         }
+    }
 
-        if let Some(dirs) = dirs {
-            import_obj.dirs = dirs
-                .iter()
-                .map(|dir| {
-                    let raw_dir: wasmedge::WasmEdge_String = StringRef::from(*dir).into();
-                    raw_dir.Buf
-                })
-                .collect::<Vec<*const c_char>>();
-            import_obj.dirs_len = dirs.len() as u32;
+    fn wasi_import_module(&mut self) -> *mut wasmedge::WasmEdge_ImportObjectContext {
+        let import_mod_ctx = unsafe {
+            wasmedge::WasmEdge_VMGetImportModuleContext(
+                self.ctx,
+                wasmedge::WasmEdge_HostRegistration_Wasi,
+            )
+        };
+        assert!(
+            !import_mod_ctx.is_null(),
+            "WASI is not enabled in the WasmEdge config"
+        );
+        import_mod_ctx
+    }
+
+    /// Returns the signature of the exported function `func_name`, if any.
+    pub fn function_type(&mut self, func_name: impl AsRef<str>) -> Option<FuncType> {
+        let raw_func_name: wasmedge::WasmEdge_String = StringRef::from(func_name.as_ref()).into();
+        let func_type = unsafe { wasmedge::WasmEdge_VMGetFunctionType(self.ctx, raw_func_name) };
+        if func_type.is_null() {
+            None
+        } else {
+            Some(unsafe { FuncType::from_raw(func_type) })
         }
+    }
 
-        if let Some(preopens) = preopens {
-            import_obj.preopens = preopens
-                .iter()
-                .map(|preopen| {
-                    let raw_preopen: wasmedge::WasmEdge_String = StringRef::from(*preopen).into();
-                    raw_preopen.Buf
-                })
-                .collect::<Vec<*const c_char>>();
-            import_obj.preopens_len = preopens.len() as u32;
+    /// Returns the signature of the function `func_name` exported by the
+    /// registered module `mod_name`, if any.
+    pub fn function_type_registered(
+        &mut self,
+        mod_name: impl AsRef<str>,
+        func_name: impl AsRef<str>,
+    ) -> Option<FuncType> {
+        let raw_mod_name: wasmedge::WasmEdge_String = StringRef::from(mod_name.as_ref()).into();
+        let raw_func_name: wasmedge::WasmEdge_String = StringRef::from(func_name.as_ref()).into();
+        let func_type = unsafe {
+            wasmedge::WasmEdge_VMGetFunctionTypeRegistered(self.ctx, raw_mod_name, raw_func_name)
+        };
+        if func_type.is_null() {
+            None
+        } else {
+            Some(unsafe { FuncType::from_raw(func_type) })
         }
+    }
 
-        unsafe {
-            wasmedge::WasmEdge_ImportObjectInitWASI(
-                import_mod_ctx,
-                import_obj.args.as_ptr(),
-                import_obj.args_len,
-                import_obj.envs.as_ptr(),
-                import_obj.envs_len,
-                import_obj.dirs.as_ptr(),
-                import_obj.dirs_len,
-                import_obj.preopens.as_ptr(),
-                import_obj.preopens_len,
+    /// Executes the exported function `func_name` on raw values, without
+    /// allocating.
+    ///
+    /// The types of `params` are checked by WasmEdge, but the results are
+    /// written to `returns` as raw bits: their `Type` is always `I32`, so the
+    /// caller has to know the signature of the function to decode them.
+    pub fn execute(
+        &mut self,
+        func_name: &str,
+        params: &[wasmedge::WasmEdge_Value],
+        returns: &mut [wasmedge::WasmEdge_Value],
+    ) -> Result<(), ErrReport> {
+        let raw_func_name: wasmedge::WasmEdge_String = StringRef::from(func_name).into();
+        let res = unsafe {
+            wasmedge::WasmEdge_VMExecute(
+                self.ctx,
+                raw_func_name,
+                params.as_ptr(),
+                params.len() as u32,
+                returns.as_mut_ptr(),
+                returns.len() as u32,
             )
-        }
+        };
+        self.execution_result(res)
     }
 
     pub fn run(
@@ -139,35 +376,221 @@ impl Vm {
             .map(wasmedge::WasmEdge_Value::from)
             .collect();
 
-        let func_type = unsafe { wasmedge::WasmEdge_VMGetFunctionType(self.ctx, raw_func_name) };
-
-        assert!(
-            !func_type.is_null(),
-            "WasmEdge Vm failed to get function type!"
-        );
+        let func_type = self
+            .function_type(func_name.as_ref())
+            .ok_or(ErrorKind::FuncNotFound)?;
 
         // construct returns
-        let returns_len = unsafe { wasmedge::WasmEdge_FunctionTypeGetReturnsLength(func_type) };
-        let mut returns = Vec::with_capacity(returns_len as usize);
+        let returns_len = func_type.results.len() as u32;
+        // zeroed, as a terminated execution does not write its results
+        let mut returns = vec![wasmedge::WasmEdge_Value::from(Value::I32(0)); returns_len as usize];
 
         // execute
-        unsafe {
-            decode_result(wasmedge::WasmEdge_VMExecute(
+        let res = unsafe {
+            wasmedge::WasmEdge_VMExecute(
                 self.ctx,
                 raw_func_name,
                 raw_params.as_ptr(),
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len,
-            ))?;
-            returns.set_len(returns_len as usize);
-        }
-        Ok(returns.into_iter().map(Into::into).collect())
+            )
+        };
+        self.execution_result(res)?;
+        // the returned values are not typed, so decode them with the function type
+        Ok(returns
+            .into_iter()
+            .zip(func_type.results)
+            .map(|(value, ty)| Value::from_raw(ty, value))
+            .collect())
+    }
+
+    /// Runs the function `func_name` exported by the registered module
+    /// `mod_name`.
+    pub fn run_registered(
+        &mut self,
+        mod_name: impl AsRef<str>,
+        func_name: impl AsRef<str>,
+        params: &[Value],
+    ) -> Result<Vec<Value>, ErrReport> {
+        let func_type = self
+            .function_type_registered(mod_name.as_ref(), func_name.as_ref())
+            .ok_or(ErrorKind::FuncNotFound)?;
+        let raw_mod_name: wasmedge::WasmEdge_String = StringRef::from(mod_name.as_ref()).into();
+        let raw_func_name: wasmedge::WasmEdge_String = StringRef::from(func_name.as_ref()).into();
+        let raw_params: Vec<_> = params
+            .iter()
+            .copied()
+            .map(wasmedge::WasmEdge_Value::from)
+            .collect();
+        let returns_len = func_type.results.len() as u32;
+        // zeroed, as a terminated execution does not write its results
+        let mut returns = vec![wasmedge::WasmEdge_Value::from(Value::I32(0)); returns_len as usize];
+
+        let res = unsafe {
+            wasmedge::WasmEdge_VMExecuteRegistered(
+                self.ctx,
+                raw_mod_name,
+                raw_func_name,
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+                returns.as_mut_ptr(),
+                returns_len,
+            )
+        };
+        self.execution_result(res)?;
+        // the returned values are not typed, so decode them with the function type
+        Ok(returns
+            .into_iter()
+            .zip(func_type.results)
+            .map(|(value, ty)| Value::from_raw(ty, value))
+            .collect())
+    }
+
+    fn execution_result(&mut self, res: wasmedge::WasmEdge_Result) -> Result<(), ErrReport> {
+        self.terminated = get_code(res) == ErrorKind::Terminated.code();
+        decode_result(res)
     }
 }
 
 impl Drop for Vm {
     fn drop(&mut self) {
+        self.statistics.detach();
         unsafe { wasmedge::WasmEdge_VMDelete(self.ctx) };
     }
 }
+
+// SAFETY: a VM owns its contexts, store, statistics and import objects, which
+// are all `Send`, and WasmEdge only keeps thread-local state for the duration
+// of a call. Calls mutate the VM, so it is not `Sync`.
+unsafe impl Send for Vm {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, Module};
+    use std::{sync::Arc, thread};
+
+    #[test]
+    fn runs_functions_across_registered_modules() {
+        // (module
+        //   (import "math" "fib" (func (param i32) (result i32)))
+        //   (func (export "fib_plus_one") (param i32) (result i32)
+        //     (i32.add (call 0 (local.get 0)) (i32.const 1))))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x06, 0x01, 0x60, 0x01, 0x7f,
+            0x01, 0x7f, 0x02, 0x0c, 0x01, 0x04, 0x6d, 0x61, 0x74, 0x68, 0x03, 0x66, 0x69, 0x62,
+            0x00, 0x00, 0x03, 0x02, 0x01, 0x00, 0x07, 0x10, 0x01, 0x0c, 0x66, 0x69, 0x62, 0x5f,
+            0x70, 0x6c, 0x75, 0x73, 0x5f, 0x6f, 0x6e, 0x65, 0x00, 0x01, 0x0a, 0x0b, 0x01, 0x09,
+            0x00, 0x20, 0x00, 0x10, 0x00, 0x41, 0x01, 0x6a, 0x0b,
+        ];
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Config::default();
+        let module = Module::load_from_buffer(&config, wasm).unwrap();
+        let fib = std::fs::read(&path).unwrap();
+
+        let mut vm = Vm::create(&config, None)
+            .register_module_from_file("math", CString::new(path.to_str().unwrap()).unwrap())
+            .unwrap()
+            .register_module_from_bytes("math2", &fib)
+            .unwrap()
+            .register_module_from_ast("math3", &Module::load_from_buffer(&config, &fib).unwrap())
+            .unwrap()
+            .load_wasm_from_ast_module(&module)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .instantiate()
+            .unwrap();
+
+        assert_eq!(
+            vm.run("fib_plus_one", &[Value::I32(5)]).unwrap(),
+            vec![Value::I32(9)]
+        );
+        for mod_name in &["math", "math2", "math3"] {
+            assert_eq!(
+                vm.run_registered(mod_name, "fib", &[Value::I32(10)])
+                    .unwrap(),
+                vec![Value::I32(89)]
+            );
+        }
+        assert_eq!(
+            vm.run_registered("math", "fac", &[]).unwrap_err().kind,
+            ErrorKind::FuncNotFound
+        );
+    }
+
+    #[test]
+    fn reports_missing_functions() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Config::default();
+        let module =
+            Module::load_from_file(&config, CString::new(path.to_str().unwrap()).unwrap()).unwrap();
+        let mut vm = Vm::create(&config, None)
+            .load_wasm_from_ast_module(&module)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .instantiate()
+            .unwrap();
+
+        assert_eq!(
+            vm.run("fac", &[Value::I32(5)]).unwrap_err().kind,
+            ErrorKind::FuncNotFound
+        );
+        assert_eq!(
+            vm.run("fib", &[Value::I32(5)]).unwrap(),
+            vec![Value::I32(8)]
+        );
+    }
+
+    #[test]
+    fn runs_vms_built_from_a_shared_module_concurrently() {
+        fn assert_send<T: Send>() {}
+        fn assert_sync<T: Sync>() {}
+        assert_send::<Vm>();
+        assert_sync::<Config>();
+        assert_sync::<Module>();
+
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("tools/wasmedge/examples/fibonacci.wasm");
+        let config = Arc::new(Config::default());
+        let module = Arc::new(
+            Module::load_from_file(&config, CString::new(path.to_str().unwrap()).unwrap()).unwrap(),
+        );
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let config = Arc::clone(&config);
+                let module = Arc::clone(&module);
+                thread::spawn(move || {
+                    let mut vm = Vm::create(&config, None)
+                        .load_wasm_from_ast_module(&module)
+                        .unwrap()
+                        .validate()
+                        .unwrap()
+                        .instantiate()
+                        .unwrap();
+                    vm.run("fib", &[Value::I32(10 + i)]).unwrap()[0].as_i32()
+                })
+            })
+            .collect();
+        let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(results, vec![Some(89), Some(144), Some(233), Some(377)]);
+
+        // A VM can be moved to another thread once instantiated.
+        let mut vm = Vm::create(&config, None)
+            .load_wasm_from_ast_module(&module)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .instantiate()
+            .unwrap();
+        let result = thread::spawn(move || vm.run("fib", &[Value::I32(5)]).unwrap()[0].as_i32())
+            .join()
+            .unwrap();
+        assert_eq!(result, Some(8));
+    }
+}
@@ -689,6 +689,9 @@ WasmEdge_StatisticsSetCostTable(WasmEdge_StatisticsContext *Cxt,
 /// The WASM execution will be aborted if the instruction costs exceeded the
 /// limit and the ErrCode::CostLimitExceeded will be returned.
 ///
+/// This function is thread-safe: the limit can be lowered from another thread
+/// to stop an execution in progress.
+///
 /// \param Cxt the WasmEdge_StatisticsContext to set the cost table.
 /// \param Limit the cost limit.
 WASMEDGE_CAPI_EXPORT extern void
@@ -2313,8 +2316,8 @@ WasmEdge_ImportObjectCreate(const WasmEdge_String ModuleName);
 /// \param Envs the environment variables in the format `ENV=VALUE`. NULL if the
 /// length is 0.
 /// \param EnvLen the length of the environment variables.
-/// \param Dirs the directory mappings in the format `PATH1:PATH2`. NULL if the
-/// length is 0.
+/// \param Dirs the directory mappings in the format `GUEST_PATH:HOST_PATH`,
+/// optionally followed by `:readonly` to deny writes. NULL if the length is 0.
 /// \param DirLen the length of the directory mappings.
 /// \param Preopens the directory paths to preopen. NULL if the length is 0.
 /// \param PreopenLen the length of the directory paths to preopen.
@@ -2338,8 +2341,8 @@ WasmEdge_ImportObjectCreateWASI(const char *const *Args, const uint32_t ArgLen,
 /// \param Envs the environment variables in the format `ENV=VALUE`. NULL if the
 /// length is 0.
 /// \param EnvLen the length of the environment variables.
-/// \param Dirs the directory mappings in the format `PATH1:PATH2`. NULL if the
-/// length is 0.
+/// \param Dirs the directory mappings in the format `GUEST_PATH:HOST_PATH`,
+/// optionally followed by `:readonly` to deny writes. NULL if the length is 0.
 /// \param DirLen the length of the directory mappings.
 /// \param Preopens the directory paths to preopen. NULL if the length is 0.
 /// \param PreopenLen the length of the directory paths to preopen.
@@ -2349,6 +2352,39 @@ WASMEDGE_CAPI_EXPORT extern void WasmEdge_ImportObjectInitWASI(
     const char *const *Dirs, const uint32_t DirLen, const char *const *Preopens,
     const uint32_t PreopenLen);
 
+/// Redirect the standard streams of the WASI import object.
+///
+/// The host file descriptors are duplicated, so the caller keeps the ownership
+/// of them. This function should be called after
+/// `WasmEdge_ImportObjectInitWASI`, which resets the standard streams.
+///
+/// \param Cxt the WasmEdge_ImportObjectContext of WASI import object.
+/// \param StdIn the host file descriptor of the standard input, or -1 to keep
+/// the standard input of the host.
+/// \param StdOut the host file descriptor of the standard output, or -1 to
+/// keep the standard output of the host.
+/// \param StdErr the host file descriptor of the standard error, or -1 to
+/// keep the standard error of the host.
+///
+/// \returns true if succeeded, false if a descriptor cannot be duplicated or
+/// the platform does not support it.
+WASMEDGE_CAPI_EXPORT extern bool
+WasmEdge_ImportObjectWasiSetStdio(WasmEdge_ImportObjectContext *Cxt,
+                                  const int32_t StdIn, const int32_t StdOut,
+                                  const int32_t StdErr);
+
+/// Get the exit code of the WASI import object.
+///
+/// The exit code is the one passed to `proc_exit` by the guest, and is reset
+/// to 0 by `WasmEdge_ImportObjectInitWASI`.
+///
+/// \param Cxt the WasmEdge_ImportObjectContext of WASI import object.
+///
+/// \returns the exit code, or `EXIT_FAILURE` if `Cxt` is not a WASI import
+/// object.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_ImportObjectWasiGetExitCode(const WasmEdge_ImportObjectContext *Cxt);
+
 /// Creation of the WasmEdge_ImportObjectContext for the wasmedge_process
 /// specification.
 ///
@@ -16,6 +16,7 @@
 #include "common/span.h"
 #include "common/timer.h"
 
+#include <atomic>
 #include <vector>
 
 namespace WasmEdge {
@@ -68,15 +69,21 @@ public:
   uint64_t getTotalCost() const { return CostSum; }
   uint64_t &getTotalCostRef() { return CostSum; }
 
-  /// Getter and setter of cost limit.
-  void setCostLimit(uint64_t Lim) { CostLimit = Lim; }
-  uint64_t getCostLimit() const { return CostLimit; }
+  /// Getter and setter of cost limit. The limit can be set from another
+  /// thread to stop a running execution.
+  void setCostLimit(uint64_t Lim) {
+    CostLimit.store(Lim, std::memory_order_relaxed);
+  }
+  uint64_t getCostLimit() const {
+    return CostLimit.load(std::memory_order_relaxed);
+  }
 
   /// Add cost and return false if exceeded limit.
   bool addCost(const uint64_t &Cost) {
+    const uint64_t Limit = getCostLimit();
     CostSum += Cost;
-    if (unlikely(CostSum > CostLimit)) {
-      CostSum = CostLimit;
+    if (unlikely(CostSum > Limit)) {
+      CostSum = Limit;
       return false;
     }
     return true;
@@ -134,7 +141,7 @@ public:
 private:
   std::vector<uint64_t> CostTab;
   uint64_t InstrCnt;
-  uint64_t CostLimit;
+  std::atomic<uint64_t> CostLimit;
   uint64_t CostSum;
   Timer::Timer TimeRecorder;
 };
@@ -38,6 +38,14 @@ public:
 
   void fini() noexcept;
 
+  /// Replace a standard stream of the guest by a duplicate of a file
+  /// descriptor of the host.
+  ///
+  /// @param[in] Fd The standard stream to replace, from 0 to 2.
+  /// @param[in] HostFd The file descriptor of the host.
+  /// @return Nothing or WASI error
+  WasiExpect<void> setStdio(__wasi_fd_t Fd, int HostFd) noexcept;
+
   constexpr const std::vector<std::string> &getArguments() const noexcept {
     return Arguments;
   }
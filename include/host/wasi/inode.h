@@ -157,6 +157,13 @@ public:
 
   static INode stdErr() noexcept;
 
+  /// Duplicate a file descriptor of the host, e.g. to use it as a standard
+  /// stream of the guest.
+  ///
+  /// @param[in] Fd The file descriptor to duplicate.
+  /// @return The duplicated file descriptor, or WASI error.
+  static WasiExpect<INode> fromHostFd(int Fd) noexcept;
+
   /// Open a file or directory.
   ///
   /// @param[in] Path The absolut path of the file or directory to open.
@@ -12,6 +12,7 @@ public:
   WasiModule();
 
   WASI::Environ &getEnv() { return Env; }
+  const WASI::Environ &getEnv() const { return Env; }
 
 private:
   WASI::Environ Env;
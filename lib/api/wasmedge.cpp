@@ -2154,6 +2154,41 @@ WASMEDGE_CAPI_EXPORT void WasmEdge_ImportObjectInitWASI(
   WasiEnv.init(DirVec, ProgName, ArgVec, EnvVec);
 }
 
+WASMEDGE_CAPI_EXPORT bool
+WasmEdge_ImportObjectWasiSetStdio(WasmEdge_ImportObjectContext *Cxt,
+                                  const int32_t StdIn, const int32_t StdOut,
+                                  const int32_t StdErr) {
+  if (!Cxt) {
+    return false;
+  }
+  auto *WasiMod =
+      dynamic_cast<WasmEdge::Host::WasiModule *>(fromImpObjCxt(Cxt));
+  if (!WasiMod) {
+    return false;
+  }
+  auto &WasiEnv = WasiMod->getEnv();
+  const int32_t HostFds[] = {StdIn, StdOut, StdErr};
+  for (uint32_t I = 0; I < 3; I++) {
+    if (HostFds[I] >= 0 && !WasiEnv.setStdio(I, HostFds[I])) {
+      return false;
+    }
+  }
+  return true;
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t
+WasmEdge_ImportObjectWasiGetExitCode(const WasmEdge_ImportObjectContext *Cxt) {
+  if (!Cxt) {
+    return EXIT_FAILURE;
+  }
+  const auto *WasiMod =
+      dynamic_cast<const WasmEdge::Host::WasiModule *>(fromImpObjCxt(Cxt));
+  if (!WasiMod) {
+    return EXIT_FAILURE;
+  }
+  return WasiMod->getEnv().getExitCode();
+}
+
 WASMEDGE_CAPI_EXPORT WasmEdge_ImportObjectContext *
 WasmEdge_ImportObjectCreateWasmEdgeProcess(const char *const *AllowedCmds,
                                            const uint32_t CmdsLen,
@@ -45,6 +45,8 @@ static inline constexpr const __wasi_rights_t kStdErrDefaultRights =
     kStdOutDefaultRights;
 static inline constexpr const __wasi_rights_t kNoInheritingRights =
     static_cast<__wasi_rights_t>(0);
+static inline constexpr const std::string_view kReadOnlySuffix =
+    ":readonly"sv;
 
 } // namespace
 
@@ -54,19 +56,23 @@ void Environ::init(Span<const std::string> Dirs, std::string ProgramName,
     /// Open dir for WASI environment.
     std::vector<std::shared_ptr<VINode>> PreopenedDirs;
     PreopenedDirs.reserve(Dirs.size());
-    for (const auto &Dir : Dirs) {
+    for (std::string_view Dir : Dirs) {
+      /// A `:readonly` suffix binds the directory without write rights.
+      __wasi_rights_t Rights = kReadRights | kWriteRights | kCreateRights;
+      if (Dir.size() > kReadOnlySuffix.size() &&
+          Dir.substr(Dir.size() - kReadOnlySuffix.size()) == kReadOnlySuffix) {
+        Dir.remove_suffix(kReadOnlySuffix.size());
+        Rights = kReadRights;
+      }
       const auto Pos = Dir.find(':');
       if (Pos != std::string::npos) {
-        const auto HostDir = Dir.substr(Pos + 1);
-        auto GuestDir =
-            VINode::canonicalGuest(std::string_view(Dir).substr(0, Pos));
+        std::string HostDir(Dir.substr(Pos + 1));
+        auto GuestDir = VINode::canonicalGuest(Dir.substr(0, Pos));
         if (GuestDir.size() == 0) {
           GuestDir = '/';
         }
-        if (auto Res =
-                VINode::bind(FS, kReadRights | kWriteRights | kCreateRights,
-                             kReadRights | kWriteRights | kCreateRights,
-                             std::move(GuestDir), std::move(HostDir));
+        if (auto Res = VINode::bind(FS, Rights, Rights, std::move(GuestDir),
+                                    std::move(HostDir));
             unlikely(!Res)) {
           spdlog::error("Bind guest directory failed:{}", Res.error());
           continue;
@@ -103,6 +109,33 @@ void Environ::init(Span<const std::string> Dirs, std::string ProgramName,
   ExitCode = 0;
 }
 
+WasiExpect<void> Environ::setStdio(__wasi_fd_t Fd, int HostFd) noexcept {
+  __wasi_rights_t Rights;
+  switch (Fd) {
+  case 0:
+    Rights = kStdInDefaultRights;
+    break;
+  case 1:
+    Rights = kStdOutDefaultRights;
+    break;
+  case 2:
+    Rights = kStdErrDefaultRights;
+    break;
+  default:
+    return WasiUnexpect(__WASI_ERRNO_BADF);
+  }
+
+  auto Node = INode::fromHostFd(HostFd);
+  if (unlikely(!Node)) {
+    return WasiUnexpect(Node);
+  }
+  std::unique_lock<std::shared_mutex> Lock(FdMutex);
+  FdMap.insert_or_assign(Fd, std::make_shared<VINode>(FS, std::move(*Node),
+                                                      Rights,
+                                                      kNoInheritingRights));
+  return {};
+}
+
 void Environ::fini() noexcept {
   EnvironVariables.clear();
   Arguments.clear();
@@ -95,6 +95,16 @@ INode INode::stdOut() noexcept { return INode(STDOUT_FILENO); }
 
 INode INode::stdErr() noexcept { return INode(STDERR_FILENO); }
 
+WasiExpect<INode> INode::fromHostFd(int Fd) noexcept {
+  /// Skip the standard streams, which are never closed.
+  if (auto NewFd = ::fcntl(Fd, F_DUPFD_CLOEXEC, STDERR_FILENO + 1);
+      unlikely(NewFd < 0)) {
+    return WasiUnexpect(fromErrNo(errno));
+  } else {
+    return INode(NewFd);
+  }
+}
+
 WasiExpect<INode> INode::open(std::string Path, __wasi_oflags_t OpenFlags,
                               __wasi_fdflags_t FdFlags,
                               uint8_t VFSFlags) noexcept {
@@ -37,6 +37,10 @@ INode INode::stdErr() noexcept {
   return INode(winapi::GetStdHandle(winapi::STD_ERROR_HANDLE_));
 }
 
+WasiExpect<INode> INode::fromHostFd(int) noexcept {
+  return WasiUnexpect(__WASI_ERRNO_NOSYS);
+}
+
 WasiExpect<INode> INode::open(std::string, __wasi_oflags_t, __wasi_fdflags_t,
                               uint8_t) noexcept {
   return WasiUnexpect(__WASI_ERRNO_NOSYS);
@@ -1942,6 +1942,12 @@ TEST(APICoreTest, ImportObject) {
   EXPECT_TRUE(true);
   WasmEdge_ImportObjectInitWASI(ImpObj, Args, 2, Envs, 3, Dirs, 1, Preopens, 4);
   EXPECT_TRUE(true);
+  EXPECT_FALSE(WasmEdge_ImportObjectWasiSetStdio(nullptr, -1, -1, -1));
+  EXPECT_TRUE(WasmEdge_ImportObjectWasiSetStdio(ImpObj, -1, -1, -1));
+  EXPECT_FALSE(WasmEdge_ImportObjectWasiSetStdio(ImpObj, -1, 65535, -1));
+  EXPECT_EQ(WasmEdge_ImportObjectWasiGetExitCode(nullptr),
+            static_cast<uint32_t>(EXIT_FAILURE));
+  EXPECT_EQ(WasmEdge_ImportObjectWasiGetExitCode(ImpObj), 0U);
   WasmEdge_VMDelete(VM);
 
   /// Create wasmedge_process.
@@ -1974,6 +1980,65 @@ TEST(APICoreTest, ImportObject) {
   WasmEdge_VMDelete(VM);
 }
 
+TEST(APICoreTest, WasiReadOnlyDir) {
+  /// (module
+  ///   (import "wasi_snapshot_preview1" "fd_fdstat_get"
+  ///     (func (param i32 i32) (result i32)))
+  ///   (memory (export "memory") 1)
+  ///   (func (export "rights") (param i32) (result i64)
+  ///     (drop (call 0 (local.get 0) (i32.const 0)))
+  ///     (i64.load offset=8 (i32.const 0))))
+  std::vector<uint8_t> Wasm = {
+      0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x0c, 0x02, 0x60,
+      0x02, 0x7f, 0x7f, 0x01, 0x7f, 0x60, 0x01, 0x7f, 0x01, 0x7e, 0x02, 0x28,
+      0x01, 0x16, 0x77, 0x61, 0x73, 0x69, 0x5f, 0x73, 0x6e, 0x61, 0x70, 0x73,
+      0x68, 0x6f, 0x74, 0x5f, 0x70, 0x72, 0x65, 0x76, 0x69, 0x65, 0x77, 0x31,
+      0x0d, 0x66, 0x64, 0x5f, 0x66, 0x64, 0x73, 0x74, 0x61, 0x74, 0x5f, 0x67,
+      0x65, 0x74, 0x00, 0x00, 0x03, 0x02, 0x01, 0x01, 0x05, 0x03, 0x01, 0x00,
+      0x01, 0x07, 0x13, 0x02, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02,
+      0x00, 0x06, 0x72, 0x69, 0x67, 0x68, 0x74, 0x73, 0x00, 0x01, 0x0a, 0x10,
+      0x01, 0x0e, 0x00, 0x20, 0x00, 0x41, 0x00, 0x10, 0x00, 0x1a, 0x41, 0x00,
+      0x29, 0x03, 0x08, 0x0b};
+  /// `__WASI_RIGHTS_FD_READ`, `__WASI_RIGHTS_FD_WRITE`, and
+  /// `__WASI_RIGHTS_PATH_CREATE_FILE`.
+  const uint64_t FdRead = UINT64_C(1) << 1;
+  const uint64_t FdWrite = UINT64_C(1) << 6;
+  const uint64_t PathCreateFile = UINT64_C(1) << 10;
+
+  /// Get the rights of the only preopened directory, bound by `Dir`.
+  auto PreopenRights = [&Wasm](const char *Dir) {
+    WasmEdge_ConfigureContext *Conf = WasmEdge_ConfigureCreate();
+    WasmEdge_ConfigureAddHostRegistration(Conf,
+                                          WasmEdge_HostRegistration_Wasi);
+    WasmEdge_VMContext *VM = WasmEdge_VMCreate(Conf, nullptr);
+    WasmEdge_ConfigureDelete(Conf);
+    WasmEdge_ImportObjectContext *ImpObj =
+        WasmEdge_VMGetImportModuleContext(VM, WasmEdge_HostRegistration_Wasi);
+    WasmEdge_ImportObjectInitWASI(ImpObj, nullptr, 0, nullptr, 0, &Dir, 1,
+                                  nullptr, 0);
+
+    /// The preopened directory follows the standard streams.
+    WasmEdge_String FuncName = WasmEdge_StringCreateByCString("rights");
+    WasmEdge_Value P[1], R[1];
+    P[0] = WasmEdge_ValueGenI32(3);
+    R[0] = WasmEdge_ValueGenI64(0);
+    EXPECT_TRUE(WasmEdge_ResultOK(WasmEdge_VMRunWasmFromBuffer(
+        VM, Wasm.data(), static_cast<uint32_t>(Wasm.size()), FuncName, P, 1, R,
+        1)));
+    WasmEdge_StringDelete(FuncName);
+    WasmEdge_VMDelete(VM);
+    return static_cast<uint64_t>(WasmEdge_ValueGetI64(R[0]));
+  };
+
+  /// Read-write binding.
+  uint64_t Rights = PreopenRights("/data:.");
+  EXPECT_EQ(Rights & (FdRead | FdWrite | PathCreateFile),
+            FdRead | FdWrite | PathCreateFile);
+  /// Read-only binding.
+  Rights = PreopenRights("/data:.:readonly");
+  EXPECT_EQ(Rights & (FdRead | FdWrite | PathCreateFile), FdRead);
+}
+
 TEST(APICoreTest, VM) {
   WasmEdge_ConfigureContext *Conf = WasmEdge_ConfigureCreate();
   WasmEdge_ConfigureAddHostRegistration(Conf, WasmEdge_HostRegistration_Wasi);